@@ -0,0 +1,88 @@
+use crate::{
+    positions::{
+        GameOutcome, Position, WIDTH, array_position::ArrayPosition, load_starting_position,
+        try_load_starting_position,
+    },
+    solvers::{SolveMode, Solver, finished_game_score, registry::build_solver},
+};
+
+/*
+    scores the columns of position after position with the same solver, which keeps its
+    transposition table from a search to the next
+*/
+pub struct ColumnScorer {
+    solver: Box<dyn Solver>,
+    mode: SolveMode,
+}
+
+impl ColumnScorer {
+    pub fn new(solver_name: &str, table_size: usize) -> Result<Self, String> {
+        let (solver, mode) = build_solver(solver_name, "", table_size)
+            .ok_or_else(|| format!("unknown solver '{}'", solver_name))?;
+        Ok(Self { solver, mode })
+    }
+
+    pub fn mode(&self) -> SolveMode {
+        self.mode
+    }
+
+    // see column_scores
+    pub fn scores(&mut self, encoded_position: &str) -> Result<[Option<i32>; WIDTH], String> {
+        let mut position = ArrayPosition::new();
+        if try_load_starting_position(encoded_position, &mut position)? != GameOutcome::Ongoing {
+            return Err("the game is already over".to_string());
+        }
+
+        let mut scores = [None; WIDTH];
+        for (colm, score) in scores.iter_mut().enumerate() {
+            if !position.can_play(colm) {
+                continue;
+            }
+            let child = format!("{}{}", encoded_position, colm + 1);
+            let mut child_position = ArrayPosition::new();
+            let outcome = load_starting_position(&child, &mut child_position);
+            // the scores of the children are from the point of view of the opponent
+            let child_score = match finished_game_score(outcome, child.len()) {
+                Some(child_score) => child_score,
+                None => {
+                    self.solver.set_position(&child);
+                    self.solver.solve(self.mode)
+                }
+            };
+            // only the sign of a weak score is meaningful
+            *score = Some(if self.mode == SolveMode::Weak {
+                -child_score.signum()
+            } else {
+                -child_score
+            });
+        }
+        Ok(scores)
+    }
+}
+
+/*
+    score of every column for the player to move in an ongoing game, None for the full columns
+    an error for an unknown solver, invalid moves or a finished game
+*/
+pub fn column_scores(
+    solver_name: &str,
+    encoded_position: &str,
+    table_size: usize,
+) -> Result<[Option<i32>; WIDTH], String> {
+    ColumnScorer::new(solver_name, table_size)?.scores(encoded_position)
+}
+
+// the column with the highest score, ties are broken in favour of the center
+pub fn best_column(scores: &[Option<i32>; WIDTH]) -> Option<usize> {
+    let mut best: Option<(usize, i32)> = None;
+    for ind in 0..WIDTH {
+        // [3, 2, 4, 1, 5, 0, 6]
+        let colm = (WIDTH as i32 / 2 + (1 - 2 * (ind as i32 & 1)) * (ind as i32 + 1) / 2) as usize;
+        if let Some(score) = scores[colm]
+            && best.is_none_or(|(_, best_score)| score > best_score)
+        {
+            best = Some((colm, score));
+        }
+    }
+    best.map(|(colm, _)| colm)
+}
@@ -0,0 +1,61 @@
+use crate::{
+    analysis::{best_column, column_scores},
+    positions::{advance_bit_position::AdvanceBitPosition, try_load_starting_position},
+    score::Outcome,
+};
+
+// the review of one move of a game, scores are from the point of view of the player who moved
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MoveAnnotation {
+    // number of moves played before this one
+    pub ply: usize,
+    // 1 - the player who moved first, 2 - the second player
+    pub player: usize,
+    pub colm: usize,
+    pub score: i32,
+    pub best_colm: usize,
+    pub best_score: i32,
+}
+
+impl MoveAnnotation {
+    // what the move gave away compared to the best column, 0 for a best move
+    pub fn lost(&self) -> i32 {
+        self.best_score - self.score
+    }
+
+    // the move turns a win into a draw or a loss, or a draw into a loss
+    pub fn is_blunder(&self) -> bool {
+        Outcome::of(self.score) != Outcome::of(self.best_score)
+    }
+}
+
+/*
+    replays the moves and scores each of them from the from_ply-th on against the best column
+    of the position it was played in, the moves must be a valid game: no full column, nothing
+    after a win. The openings take by far the longest to solve, from_ply skips them
+*/
+pub fn annotate_game(
+    solver_name: &str,
+    moves: &str,
+    from_ply: usize,
+    table_size: usize,
+) -> Result<Vec<MoveAnnotation>, String> {
+    // the whole game is checked before anything is solved
+    try_load_starting_position(moves, &mut AdvanceBitPosition::new())?;
+    let colms: Vec<usize> = moves.bytes().map(|ch| (ch - b'1') as usize).collect();
+
+    let mut annotations = Vec::with_capacity(colms.len().saturating_sub(from_ply));
+    for (ply, &colm) in colms.iter().enumerate().skip(from_ply) {
+        let scores = column_scores(solver_name, &moves[..ply], table_size)?;
+        let best_colm = best_column(&scores).expect("a playable column has a score");
+        annotations.push(MoveAnnotation {
+            ply,
+            player: 1 + (ply & 1),
+            colm,
+            score: scores[colm].expect("the played column has a score"),
+            best_colm,
+            best_score: scores[best_colm].expect("the best column is playable"),
+        });
+    }
+    Ok(annotations)
+}
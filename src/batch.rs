@@ -0,0 +1,108 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+use crate::{
+    positions::{array_position::ArrayPosition, load_starting_position},
+    solvers::{
+        SolveMode, Solver, finished_game_score,
+        registry::{BuildOptions, build_solver_with, find_solver},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BatchSolution {
+    pub score: i32,
+    pub explored_nodes: usize,
+    pub time_in_microseconds: usize,
+}
+
+/*
+    solves the positions on a pool of threads, each thread builds one solver with its own
+    transposition table and moves it from position to position, the solutions are in the order
+    of the positions. Finished games get their final score without a search.
+    None if there is no such solver, panics on invalid positions like build_solver
+*/
+pub fn solve_batch(
+    solver_name: &str,
+    positions: &[&str],
+    options: &BuildOptions,
+    threads: usize,
+) -> Option<Vec<BatchSolution>> {
+    find_solver(solver_name)?;
+    let threads = threads.clamp(1, positions.len().max(1));
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut solutions = vec![None; positions.len()];
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                let mut solver: Option<(Box<dyn Solver>, SolveMode)> = None;
+                loop {
+                    let ind = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&position) = positions.get(ind) else {
+                        break;
+                    };
+                    let solution = solve_one(solver_name, position, options, &mut solver);
+                    if sender.send((ind, solution)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        for (ind, solution) in receiver {
+            solutions[ind] = Some(solution);
+        }
+    });
+    Some(
+        solutions
+            .into_iter()
+            .map(|solution| solution.expect("every position is solved"))
+            .collect(),
+    )
+}
+
+fn solve_one(
+    solver_name: &str,
+    position: &str,
+    options: &BuildOptions,
+    solver: &mut Option<(Box<dyn Solver>, SolveMode)>,
+) -> BatchSolution {
+    let outcome = load_starting_position(position, &mut ArrayPosition::new());
+    if let Some(score) = finished_game_score(outcome, position.len()) {
+        return BatchSolution {
+            score,
+            explored_nodes: 0,
+            time_in_microseconds: 0,
+        };
+    }
+
+    let (solver, mode) = match solver {
+        Some((solver, mode)) => {
+            solver.set_position(position);
+            (solver, *mode)
+        }
+        None => {
+            let built = build_solver_with(solver_name, position, options)
+                .expect("the solver name is validated");
+            let (solver, mode) = solver.insert(built);
+            (solver, *mode)
+        }
+    };
+    let start = Instant::now();
+    let score = solver.solve(mode);
+    BatchSolution {
+        score,
+        explored_nodes: solver.explored_nodes(),
+        time_in_microseconds: start.elapsed().as_micros() as usize,
+    }
+}
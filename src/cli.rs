@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+
+pub struct OptionSpec {
+    pub name: &'static str,
+    // placeholder shown in the help, None for flags without a value
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub options: &'static [OptionSpec],
+}
+
+const SOLVER: OptionSpec = OptionSpec {
+    name: "--solver",
+    value: Some("NAME"),
+    help: "solver from --list-solvers, strong-move-score by default",
+};
+
+const POSITION: OptionSpec = OptionSpec {
+    name: "--position",
+    value: Some("MOVES"),
+    help: "played columns 1-7, read from stdin when missing",
+};
+
+const TABLEBASE: OptionSpec = OptionSpec {
+    name: "--tablebase",
+    value: Some("FILE"),
+    help: "endgame tablebase written by the tablebase command, \
+           probed by the move score and pvs solvers",
+};
+
+const GUESS: OptionSpec = OptionSpec {
+    name: "--guess",
+    value: Some("SCORE"),
+    help: "first guess of the score for the mtdf and aspiration solvers, 0 by default",
+};
+
+const VERBOSE: OptionSpec = OptionSpec {
+    name: "--verbose",
+    value: None,
+    help: "print the searches of the root and the explored nodes to stderr while solving",
+};
+
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "solve",
+        help: "print the score, explored nodes and time in microseconds of a position",
+        options: &[SOLVER, POSITION, TABLEBASE, GUESS, VERBOSE],
+    },
+    CommandSpec {
+        name: "analyze",
+        help: "print the score of every column of a position",
+        options: &[SOLVER, POSITION],
+    },
+    CommandSpec {
+        name: "annotate",
+        help: "score every move of a game against the best column and flag the blunders",
+        options: &[
+            SOLVER,
+            POSITION,
+            OptionSpec {
+                name: "--from",
+                value: Some("PLY"),
+                help: "skip the first PLY moves, the openings are the slowest to solve",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "play",
+        help: "play against the solver",
+        options: &[
+            SOLVER,
+            OptionSpec {
+                name: "--position",
+                value: Some("MOVES"),
+                help: "starting position, the empty board by default",
+            },
+            OptionSpec {
+                name: "--second",
+                value: None,
+                help: "let the solver make the first move",
+            },
+            OptionSpec {
+                name: "--level",
+                value: Some("LEVEL"),
+                help: "easy, medium, hard or perfect (default)",
+            },
+            OptionSpec {
+                name: "--seed",
+                value: Some("SEED"),
+                help: "seed of the random choices, taken from the clock by default",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "bench",
+        help: "solve every \"moves score\" line of the datasets and report the metrics",
+        options: &[
+            SOLVER,
+            OptionSpec {
+                name: "--dataset",
+                value: Some("FILE"),
+                help: "dataset file, can be repeated",
+            },
+            TABLEBASE,
+            GUESS,
+        ],
+    },
+    CommandSpec {
+        name: "batch",
+        help: "solve every position of a file on a pool of threads and write \"moves score\" lines \
+               in the order of the file",
+        options: &[
+            SOLVER,
+            OptionSpec {
+                name: "--in",
+                value: Some("FILE"),
+                help: "positions, one per line, anything after the moves is ignored",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("FILE"),
+                help: "file the scores are written to, stdout by default",
+            },
+            OptionSpec {
+                name: "--threads",
+                value: Some("N"),
+                help: "worker threads, each with its own solver and transposition table, \
+                       all the cores by default",
+            },
+            TABLEBASE,
+            GUESS,
+        ],
+    },
+    CommandSpec {
+        name: "gen-dataset",
+        help: "label random positions with a strong solver and write them as \"moves score\" \
+               lines, bucketed like the Test_Lx_Ry datasets by their remaining moves",
+        options: &[
+            SOLVER,
+            OptionSpec {
+                name: "--moves",
+                value: Some("N"),
+                help: "played moves of the positions, the random playouts avoid losing moves",
+            },
+            OptionSpec {
+                name: "--remaining",
+                value: Some("MIN-MAX"),
+                help: "moves to the end of the game under perfect play that are kept, any by default",
+            },
+            OptionSpec {
+                name: "--count",
+                value: Some("N"),
+                help: "number of positions, 1000 by default",
+            },
+            OptionSpec {
+                name: "--seed",
+                value: Some("SEED"),
+                help: "seed of the playouts, taken from the clock by default",
+            },
+            OptionSpec {
+                name: "--threads",
+                value: Some("N"),
+                help: "threads solving the positions, all the cores by default",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("DIR"),
+                help: "directory the datasets are written to, one new file per bucket, \
+                       stdout by default",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "verify",
+        help: "check every line of a dataset and solve its positions again, the invalid lines \
+               and the wrong scores are printed with their line number",
+        options: &[
+            SOLVER,
+            OptionSpec {
+                name: "--dataset",
+                value: Some("FILE"),
+                help: "dataset file, can be repeated",
+            },
+            OptionSpec {
+                name: "--threads",
+                value: Some("N"),
+                help: "threads solving the positions, all the cores by default",
+            },
+            TABLEBASE,
+            GUESS,
+        ],
+    },
+    CommandSpec {
+        name: "perft",
+        help: "count the move sequences of every length up to --depth with each position \
+               representation, a winning move ends a sequence",
+        options: &[
+            OptionSpec {
+                name: "--position",
+                value: Some("MOVES"),
+                help: "position the sequences start from, the empty board by default",
+            },
+            OptionSpec {
+                name: "--depth",
+                value: Some("N"),
+                help: "maximal number of moves of the sequences",
+            },
+            OptionSpec {
+                name: "--representation",
+                value: Some("NAME"),
+                help: "only count with array, bit or advance-bit, all of them by default",
+            },
+            OptionSpec {
+                name: "--unique",
+                value: None,
+                help: "count the distinct positions reached instead of the sequences",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "enumerate",
+        help: "count the distinct positions of every ply up to --ply and write their moves",
+        options: &[
+            OptionSpec {
+                name: "--position",
+                value: Some("MOVES"),
+                help: "position the enumeration starts from, the empty board by default",
+            },
+            OptionSpec {
+                name: "--ply",
+                value: Some("N"),
+                help: "number of moves of the last enumerated positions",
+            },
+            OptionSpec {
+                name: "--symmetric",
+                value: None,
+                help: "count a position and its mirror image once",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("FILE"),
+                help: "file the moves of every position are written to, one per line",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "tablebase",
+        help: "compute the exact score of every position with at least --stones stones \
+               reachable from a position by retrograde analysis and write them to a file",
+        options: &[
+            OptionSpec {
+                name: "--position",
+                value: Some("MOVES"),
+                help: "position the stored positions are reached from, the empty board by default",
+            },
+            OptionSpec {
+                name: "--stones",
+                value: Some("K"),
+                help: "minimal number of stones of the stored positions",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("FILE"),
+                help: "file the tablebase is written to",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "record",
+        help: "write a game record with headers from a move string or convert a record back",
+        options: &[
+            POSITION,
+            OptionSpec {
+                name: "--in",
+                value: Some("FILE"),
+                help: "game record to read instead of a move string",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("FILE"),
+                help: "file the output is written to, stdout by default",
+            },
+            OptionSpec {
+                name: "--moves",
+                value: None,
+                help: "output the plain move string instead of the record",
+            },
+            OptionSpec {
+                name: "--player1",
+                value: Some("NAME"),
+                help: "Player1 header, the player who moves first",
+            },
+            OptionSpec {
+                name: "--player2",
+                value: Some("NAME"),
+                help: "Player2 header",
+            },
+            OptionSpec {
+                name: "--date",
+                value: Some("DATE"),
+                help: "Date header",
+            },
+            OptionSpec {
+                name: "--time-control",
+                value: Some("TC"),
+                help: "TimeControl header, e.g. 300+5",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "tree",
+        help: "solve a position and write the explored tree with its windows, values, \
+               cutoffs and table hits as Graphviz DOT or JSON",
+        options: &[
+            SOLVER,
+            POSITION,
+            OptionSpec {
+                name: "--depth",
+                value: Some("N"),
+                help: "moves below the position that are recorded, 2 by default",
+            },
+            OptionSpec {
+                name: "--format",
+                value: Some("FORMAT"),
+                help: "dot (default) or json",
+            },
+            OptionSpec {
+                name: "--out",
+                value: Some("FILE"),
+                help: "file the tree is written to, stdout by default",
+            },
+            GUESS,
+        ],
+    },
+];
+
+// solve is used when no command is given, so `project --solver NAME` keeps working
+const DEFAULT_COMMAND: &str = "solve";
+
+pub enum Invocation {
+    Run(Args),
+    Help(Option<&'static CommandSpec>),
+    ListSolvers,
+}
+
+pub struct Args {
+    pub command: &'static str,
+    values: HashMap<&'static str, Vec<String>>,
+}
+
+impl Args {
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values(name).last().map(String::as_str)
+    }
+
+    pub fn values(&self, name: &str) -> &[String] {
+        self.values.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+}
+
+fn find_command(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Invocation, String> {
+    let mut args = args.into_iter().peekable();
+
+    let explicit_command = match args.peek() {
+        Some(arg) if !arg.starts_with('-') => {
+            Some(find_command(arg).ok_or_else(|| format!("unknown command '{}'", arg))?)
+        }
+        _ => None,
+    };
+    if explicit_command.is_some() {
+        args.next();
+    }
+    let command = explicit_command.unwrap_or_else(|| find_command(DEFAULT_COMMAND).unwrap());
+
+    let mut values: HashMap<&'static str, Vec<String>> = HashMap::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Invocation::Help(explicit_command)),
+            "--list-solvers" => return Ok(Invocation::ListSolvers),
+            _ => {}
+        }
+        let option = command
+            .options
+            .iter()
+            .find(|option| option.name == arg)
+            .ok_or_else(|| format!("unknown option '{}' for '{}'", arg, command.name))?;
+        let value = match option.value {
+            Some(placeholder) => args
+                .next()
+                .ok_or_else(|| format!("missing {} after '{}'", placeholder, option.name))?,
+            None => String::new(),
+        };
+        values.entry(option.name).or_default().push(value);
+    }
+
+    Ok(Invocation::Run(Args {
+        command: command.name,
+        values,
+    }))
+}
+
+pub fn usage(command: Option<&CommandSpec>) -> String {
+    let mut usage = String::new();
+    match command {
+        None => {
+            usage.push_str("usage: project [COMMAND] [OPTIONS]\n\ncommands:\n");
+            for command in COMMANDS {
+                usage.push_str(&format!("    {:<12} {}\n", command.name, command.help));
+            }
+            usage.push_str(&format!(
+                "\nwithout a command the position is solved like with '{}'\n\
+                 run 'project COMMAND --help' for the options of each command\n",
+                DEFAULT_COMMAND
+            ));
+            usage.push_str("\noptions:\n");
+        }
+        Some(command) => {
+            usage.push_str(&format!(
+                "usage: project {} [OPTIONS]\n\n{}\n\noptions:\n",
+                command.name, command.help
+            ));
+            for option in command.options {
+                let name = match option.value {
+                    Some(placeholder) => format!("{} {}", option.name, placeholder),
+                    None => option.name.to_string(),
+                };
+                usage.push_str(&format!("    {:<20} {}\n", name, option.help));
+            }
+        }
+    }
+    usage.push_str(&format!("    {:<20} {}\n", "--list-solvers", "list the available solvers"));
+    usage.push_str(&format!("    {:<20} {}\n", "-h, --help", "print this help"));
+    usage
+}
+
+#[cfg(test)]
+mod tests;
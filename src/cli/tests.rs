@@ -0,0 +1,50 @@
+use crate::cli::{Invocation, parse};
+
+fn parse_args(args: &[&str]) -> Result<Invocation, String> {
+    parse(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn solve_is_the_default_command() {
+    let Ok(Invocation::Run(args)) = parse_args(&["--solver", "weak-move-score"]) else {
+        panic!("expected a command to run");
+    };
+    assert_eq!(args.command, "solve");
+    assert_eq!(args.value("--solver"), Some("weak-move-score"));
+    assert_eq!(args.value("--position"), None);
+}
+
+#[test]
+fn options_of_a_command() {
+    let Ok(Invocation::Run(args)) =
+        parse_args(&["bench", "--dataset", "a", "--dataset", "b", "--solver", "negamax"])
+    else {
+        panic!("expected a command to run");
+    };
+    assert_eq!(args.command, "bench");
+    assert_eq!(args.values("--dataset"), ["a", "b"]);
+    assert!(!args.flag("--position"));
+
+    let Ok(Invocation::Run(args)) = parse_args(&["play", "--second"]) else {
+        panic!("expected a command to run");
+    };
+    assert!(args.flag("--second"));
+}
+
+#[test]
+fn help_and_list() {
+    assert!(matches!(parse_args(&["--help"]), Ok(Invocation::Help(None))));
+    assert!(matches!(
+        parse_args(&["analyze", "-h"]),
+        Ok(Invocation::Help(Some(command))) if command.name == "analyze"
+    ));
+    assert!(matches!(parse_args(&["--list-solvers"]), Ok(Invocation::ListSolvers)));
+}
+
+#[test]
+fn invalid_arguments() {
+    assert!(parse_args(&["unknown"]).is_err());
+    assert!(parse_args(&["solve", "--dataset", "a"]).is_err());
+    assert!(parse_args(&["--solver"]).is_err());
+    assert!(parse_args(&["play", "--verbose"]).is_err());
+}
@@ -0,0 +1,162 @@
+use std::{sync::Arc, time::Instant};
+
+use project::{
+    positions::{GameOutcome, Position, try_load_starting_position},
+    solvers::{
+        MAX_SCORE, MIN_SCORE, SolveMode,
+        progress::{Iteration, Observer, Progress},
+        registry::{BuildOptions, find_solver},
+    },
+    tablebase::Tablebase,
+};
+
+use crate::cli::Args;
+
+pub const TRANSPOSITION_TABLE_SIZE: usize = 8388593;
+
+const DEFAULT_SOLVER: &str = "strong-move-score";
+
+pub fn solver_name(args: &Args) -> Result<&str, String> {
+    let name = args.value("--solver").unwrap_or(DEFAULT_SOLVER);
+    match find_solver(name) {
+        Some(_) => Ok(name),
+        None => Err(format!("unknown solver '{}', see --list-solvers", name)),
+    }
+}
+
+// the mode the solver name asks for, weak solvers only tell who wins
+pub fn solve_mode(solver_name: &str) -> SolveMode {
+    let (_, strength) = find_solver(solver_name).expect("the solver name is validated");
+    strength.mode()
+}
+
+// prints the progress of a solve to stderr for --verbose
+struct StderrObserver {
+    start: Instant,
+}
+
+impl Observer for StderrObserver {
+    fn iteration(&self, iteration: &Iteration, progress: &Progress) {
+        eprintln!(
+            "[{:>8.2}s] window [{}, {}] -> {}, score in [{}, {}], {} nodes, table {:.1}% full",
+            self.start.elapsed().as_secs_f64(),
+            iteration.alpha,
+            iteration.beta,
+            iteration.score,
+            iteration.left,
+            iteration.right,
+            progress.explored_nodes,
+            progress.table_fill() * 100.0
+        );
+    }
+
+    fn nodes(&self, progress: &Progress) {
+        let seconds = self.start.elapsed().as_secs_f64();
+        eprintln!(
+            "[{:>8.2}s] {} nodes, {:.0} nodes/s, table {:.1}% full",
+            seconds,
+            progress.explored_nodes,
+            progress.explored_nodes as f64 / seconds,
+            progress.table_fill() * 100.0
+        );
+    }
+
+    // about a line per second
+    fn node_interval(&self) -> usize {
+        1 << 24
+    }
+}
+
+// the transposition table size, the tablebase from --tablebase and the observer of --verbose
+pub fn build_options(args: &Args) -> Result<BuildOptions, String> {
+    let mut options = BuildOptions::new(TRANSPOSITION_TABLE_SIZE);
+    if let Some(path) = args.value("--tablebase") {
+        let tablebase = Tablebase::load(path)
+            .map_err(|err| format!("couldn't load the tablebase {}: {}", path, err))?;
+        options.tablebase = Some(Arc::new(tablebase));
+    }
+    if let Some(guess) = args.value("--guess") {
+        options.guess = guess
+            .parse()
+            .ok()
+            .filter(|guess| (MIN_SCORE..=MAX_SCORE).contains(guess))
+            .ok_or_else(|| {
+                format!(
+                    "--guess must be a score from {} to {}, got '{}'",
+                    MIN_SCORE, MAX_SCORE, guess
+                )
+            })?;
+    }
+    if args.flag("--verbose") {
+        options.observer = Some(Arc::new(StderrObserver {
+            start: Instant::now(),
+        }));
+    }
+    Ok(options)
+}
+
+// --threads, all the cores by default
+pub fn read_threads(args: &Args) -> Result<usize, String> {
+    match args.value("--threads") {
+        Some(threads) => threads
+            .parse()
+            .ok()
+            .filter(|&threads| threads > 0)
+            .ok_or_else(|| format!("--threads must be a positive number, got '{}'", threads)),
+        None => Ok(std::thread::available_parallelism().map_or(1, |threads| threads.get())),
+    }
+}
+
+// plays the moves from the user on the position, invalid moves are an error instead of a panic
+pub fn load_position<P: Position>(
+    encoded_position: &str,
+    position: &mut P,
+) -> Result<GameOutcome, String> {
+    try_load_starting_position(encoded_position, position)
+        .map_err(|err| format!("invalid starting position: {}", err))
+}
+
+// the position from --position or the first line of stdin
+pub fn read_position(args: &Args) -> Result<String, String> {
+    if let Some(position) = args.value("--position") {
+        return Ok(position.trim().to_string());
+    }
+    let mut encoded_position = String::new();
+    std::io::stdin()
+        .read_line(&mut encoded_position)
+        .map_err(|err| format!("couldn't read the encoded position: {}", err))?;
+    Ok(encoded_position.trim().to_string())
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    match args.command {
+        "solve" => solve::run(args),
+        "analyze" => analyze::run(args),
+        "annotate" => annotate::run(args),
+        "play" => play::run(args),
+        "bench" => bench::run(args),
+        "batch" => batch::run(args),
+        "gen-dataset" => gen_dataset::run(args),
+        "verify" => verify::run(args),
+        "perft" => perft::run(args),
+        "enumerate" => enumerate::run(args),
+        "tablebase" => tablebase::run(args),
+        "record" => record::run(args),
+        "tree" => tree::run(args),
+        other => unreachable!("command '{}' is not implemented", other),
+    }
+}
+
+pub mod analyze;
+pub mod annotate;
+pub mod batch;
+pub mod bench;
+pub mod enumerate;
+pub mod gen_dataset;
+pub mod perft;
+pub mod play;
+pub mod record;
+pub mod solve;
+pub mod tablebase;
+pub mod tree;
+pub mod verify;
@@ -0,0 +1,41 @@
+use project::{
+    analysis::{best_column, column_scores},
+    positions::array_position::ArrayPosition,
+    score::Interpretation,
+    solvers::finished_game_score,
+};
+
+use crate::{
+    cli::Args,
+    commands::{TRANSPOSITION_TABLE_SIZE, load_position, read_position, solve_mode, solver_name},
+};
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let encoded_position = read_position(args)?;
+
+    let mut position = ArrayPosition::new();
+    let outcome = load_position(&encoded_position, &mut position)?;
+    if finished_game_score(outcome, encoded_position.len()).is_some() {
+        return Err("the game is already over".to_string());
+    }
+
+    let mode = solve_mode(solver_name);
+    let scores = column_scores(solver_name, &encoded_position, TRANSPOSITION_TABLE_SIZE)?;
+    println!("{}", position);
+    for (colm, score) in scores.iter().enumerate() {
+        match score {
+            Some(score) => println!(
+                "column {}: {} ({})",
+                colm + 1,
+                score,
+                Interpretation::new(*score, encoded_position.len(), mode)
+            ),
+            None => println!("column {}: full", colm + 1),
+        }
+    }
+    if let Some(colm) = best_column(&scores) {
+        println!("best column: {}", colm + 1);
+    }
+    Ok(())
+}
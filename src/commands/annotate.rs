@@ -0,0 +1,44 @@
+use project::annotation::annotate_game;
+
+use crate::{
+    cli::Args,
+    commands::{TRANSPOSITION_TABLE_SIZE, read_position, solver_name},
+};
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let moves = read_position(args)?;
+    let from_ply = match args.value("--from") {
+        Some(ply) => ply
+            .parse()
+            .map_err(|_| format!("--from must be a number, got '{}'", ply))?,
+        None => 0,
+    };
+
+    let annotations = annotate_game(solver_name, &moves, from_ply, TRANSPOSITION_TABLE_SIZE)?;
+    let mut blunders = [0; 2];
+    for annotation in &annotations {
+        let marker = if annotation.is_blunder() {
+            blunders[annotation.player - 1] += 1;
+            "  ?? blunder"
+        } else {
+            ""
+        };
+        println!(
+            "{:>2}. player {} plays {}  score {:>3}  best {} ({:>3})  lost {:>2}{}",
+            annotation.ply + 1,
+            annotation.player,
+            annotation.colm + 1,
+            annotation.score,
+            annotation.best_colm + 1,
+            annotation.best_score,
+            annotation.lost(),
+            marker
+        );
+    }
+    println!(
+        "blunders: player 1 {}, player 2 {}",
+        blunders[0], blunders[1]
+    );
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    time::Instant,
+};
+
+use project::{
+    batch::solve_batch,
+    positions::{array_position::ArrayPosition, try_load_starting_position},
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, read_threads, solver_name},
+};
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let path = args.value("--in").ok_or("missing --in")?;
+    let threads = read_threads(args)?;
+
+    // the first field of every line, so labelled datasets can be solved again
+    let content =
+        std::fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {}", path, err))?;
+    let mut positions = Vec::new();
+    for (ind, line) in content.lines().enumerate() {
+        let Some(position) = line.split_whitespace().next() else {
+            continue;
+        };
+        try_load_starting_position(position, &mut ArrayPosition::new())
+            .map_err(|err| format!("{}:{}: {}", path, ind + 1, err))?;
+        positions.push(position);
+    }
+
+    let options = build_options(args)?;
+    let start = Instant::now();
+    let solutions = solve_batch(solver_name, &positions, &options, threads)
+        .expect("the solver name is validated");
+    let seconds = start.elapsed().as_secs_f64();
+
+    let mut writer: BufWriter<Box<dyn Write>> = BufWriter::new(match args.value("--out") {
+        Some(out) => {
+            Box::new(File::create(out).map_err(|err| format!("couldn't create {}: {}", out, err))?)
+        }
+        None => Box::new(std::io::stdout()),
+    });
+    for (position, solution) in positions.iter().zip(&solutions) {
+        writeln!(writer, "{} {}", position, solution.score)
+            .map_err(|err| format!("couldn't write the scores: {}", err))?;
+    }
+    writer
+        .flush()
+        .map_err(|err| format!("couldn't write the scores: {}", err))?;
+
+    let explored_nodes: usize = solutions
+        .iter()
+        .map(|solution| solution.explored_nodes)
+        .sum();
+    eprintln!(
+        "{} positions, {} explored nodes in {:.2}s with {} threads",
+        positions.len(),
+        explored_nodes,
+        seconds,
+        threads
+    );
+    Ok(())
+}
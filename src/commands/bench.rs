@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use project::{
+    positions::{array_position::ArrayPosition, try_load_starting_position},
+    solvers::registry::{BuildOptions, Strength, build_solver_with, find_solver},
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, solve::measure, solver_name},
+};
+
+// the same columns as scripts/benchmark/benchmark.py
+const HEADER: &str = "dataset,correct,time_mean_std,explore_nodes_mean_std,pos_per_sec";
+
+fn mean_and_std(values: &[f64]) -> (f64, f64) {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    if values.len() < 2 {
+        return (mean, 0.0);
+    }
+    let variance =
+        values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    (mean, variance.sqrt())
+}
+
+fn format_time_compact(microseconds: f64) -> String {
+    if microseconds >= 1_000_000.0 {
+        format!("{:.2}s", microseconds / 1_000_000.0)
+    } else if microseconds >= 1_000.0 {
+        format!("{:.2}ms", microseconds / 1_000.0)
+    } else {
+        format!("{:.2}µs", microseconds)
+    }
+}
+
+fn bench_dataset(solver_name: &str, path: &str, options: &BuildOptions) -> Result<String, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {}", path, err))?;
+    let (_, strength) = find_solver(solver_name).expect("the solver name is validated");
+
+    let mut correct = 0;
+    let mut times = Vec::new();
+    let mut nodes = Vec::new();
+    for (ind, line) in content.lines().enumerate() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        let [position, expected] = parts[..] else {
+            continue;
+        };
+        let expected: i32 = expected
+            .parse()
+            .map_err(|_| format!("invalid score '{}' in {}", expected, path))?;
+        try_load_starting_position(position, &mut ArrayPosition::new())
+            .map_err(|err| format!("{}:{}: {}", path, ind + 1, err))?;
+
+        let (mut solver, mode) = build_solver_with(solver_name, position, options)
+            .expect("the solver name is validated");
+        let metric = measure(solver.as_mut(), mode);
+        // weak solvers only have to find out who wins
+        let is_correct = match strength {
+            Strength::Weak => metric.score.signum() == expected.signum(),
+            Strength::Strong => metric.score == expected,
+        };
+        if is_correct {
+            correct += 1;
+        }
+        times.push(metric.time_in_microseconds as f64);
+        nodes.push(metric.explored_nodes as f64);
+    }
+
+    if times.is_empty() {
+        return Err(format!("{} contains no positions", path));
+    }
+    let (time_mean, time_std) = mean_and_std(&times);
+    let (nodes_mean, nodes_std) = mean_and_std(&nodes);
+    let total_seconds = times.iter().sum::<f64>() / 1_000_000.0;
+    let positions_per_second = if total_seconds > 0.0 {
+        times.len() as f64 / total_seconds
+    } else {
+        0.0
+    };
+
+    let dataset = Path::new(path)
+        .file_name()
+        .map_or(path.into(), |name| name.to_string_lossy());
+    Ok(format!(
+        "{},{}/{},{} ± {},{:.2} ± {:.2},{:.2}",
+        dataset,
+        correct,
+        times.len(),
+        format_time_compact(time_mean),
+        format_time_compact(time_std),
+        nodes_mean,
+        nodes_std,
+        positions_per_second
+    ))
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let datasets = args.values("--dataset");
+    if datasets.is_empty() {
+        return Err("missing --dataset".to_string());
+    }
+
+    let options = build_options(args)?;
+    println!("{}", HEADER);
+    for path in datasets {
+        println!("{}", bench_dataset(solver_name, path, &options)?);
+    }
+    Ok(())
+}
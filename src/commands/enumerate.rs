@@ -0,0 +1,67 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use project::{
+    enumeration::{EnumeratedPosition, enumerate_positions},
+    positions::{GameOutcome, HEIGHT, WIDTH, advance_bit_position::AdvanceBitPosition},
+};
+
+use crate::{cli::Args, commands::load_position};
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let encoded_position = args.value("--position").unwrap_or("");
+    let mut position = AdvanceBitPosition::new();
+    if load_position(encoded_position, &mut position)? != GameOutcome::Ongoing {
+        return Err("the game is already over".to_string());
+    }
+
+    let root_ply = encoded_position.len();
+    let max_ply: usize = args
+        .value("--ply")
+        .ok_or("missing --ply")?
+        .parse()
+        .ok()
+        .filter(|ply| (root_ply..=WIDTH * HEIGHT).contains(ply))
+        .ok_or_else(|| {
+            format!(
+                "--ply must be a number from {} to {}",
+                root_ply,
+                WIDTH * HEIGHT
+            )
+        })?;
+
+    let mut out = match args.value("--out") {
+        Some(path) => Some((
+            path,
+            BufWriter::new(
+                File::create(path).map_err(|err| format!("couldn't create {}: {}", path, err))?,
+            ),
+        )),
+        None => None,
+    };
+    // the first write error stops the writing, it is reported after the enumeration
+    let mut write_result = Ok(());
+    let root = EnumeratedPosition {
+        moves: encoded_position.to_string(),
+        position,
+    };
+    let counts = enumerate_positions(root, max_ply, args.flag("--symmetric"), |enumerated| {
+        if let Some((_, writer)) = &mut out
+            && write_result.is_ok()
+        {
+            write_result = writeln!(writer, "{}", enumerated.moves);
+        }
+    });
+    if let Some((path, mut writer)) = out {
+        write_result
+            .and_then(|_| writer.flush())
+            .map_err(|err| format!("couldn't write {}: {}", path, err))?;
+    }
+
+    for (ind, count) in counts.iter().enumerate() {
+        println!("{} {}", root_ply + ind, count);
+    }
+    Ok(())
+}
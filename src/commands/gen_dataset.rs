@@ -0,0 +1,169 @@
+use std::{collections::HashSet, io::Write, ops::RangeInclusive};
+
+use project::{
+    batch::solve_batch,
+    dataset::{bucket_name, random_playout},
+    positions::{HEIGHT, WIDTH, advance_bit_position::AdvanceBitPosition, from_moves},
+    random::Rng,
+    score::Interpretation,
+    solvers::SolveMode,
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, read_threads, solve_mode, solver_name},
+};
+
+const DEFAULT_COUNT: usize = 1000;
+
+// playouts tried per requested position before giving up on a remaining range nobody reaches
+const PLAYOUTS_PER_POSITION: usize = 100;
+
+fn parse_number(args: &Args, name: &str, default: Option<usize>) -> Result<usize, String> {
+    match args.value(name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("{} must be a number, got '{}'", name, value)),
+        None => default.ok_or_else(|| format!("missing {}", name)),
+    }
+}
+
+// "MIN-MAX" or a single number of remaining moves
+fn parse_remaining(args: &Args) -> Result<RangeInclusive<usize>, String> {
+    let Some(value) = args.value("--remaining") else {
+        return Ok(0..=WIDTH * HEIGHT);
+    };
+    let invalid = || format!("--remaining must be MIN-MAX or a number, got '{}'", value);
+    let (min, max) = value.split_once('-').unwrap_or((value, value));
+    let min: usize = min.parse().map_err(|_| invalid())?;
+    let max: usize = max.parse().map_err(|_| invalid())?;
+    if min > max {
+        return Err(format!(
+            "--remaining must have MIN at most MAX, got '{}'",
+            value
+        ));
+    }
+    Ok(min..=max)
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    if solve_mode(solver_name) != SolveMode::Strong {
+        return Err(format!(
+            "{} only tells who wins, the remaining moves need a strong solver",
+            solver_name
+        ));
+    }
+    let played_moves = parse_number(args, "--moves", None)?;
+    if played_moves >= WIDTH * HEIGHT {
+        return Err(format!("--moves must be less than {}", WIDTH * HEIGHT));
+    }
+    let remaining = parse_remaining(args)?;
+    if let Some(dir) = args.value("--out") {
+        check_out_dir(dir, played_moves, &remaining)?;
+    }
+    let count = parse_number(args, "--count", Some(DEFAULT_COUNT))?;
+    let seed = match args.value("--seed") {
+        Some(_) => parse_number(args, "--seed", None)? as u64,
+        None => {
+            let seed = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_err(|err| err.to_string())?
+                .as_nanos() as u64;
+            eprintln!("seed {}", seed);
+            seed
+        }
+    };
+    let threads = read_threads(args)?;
+
+    let options = build_options(args)?;
+    let mut rng = Rng::new(seed);
+    let mut seen = HashSet::new();
+    let mut playouts = 0;
+    let mut found: Vec<(String, i32, usize)> = Vec::new();
+    while found.len() < count && playouts < count * PLAYOUTS_PER_POSITION {
+        // a few candidates per thread between the batches, so every thread stays busy
+        let mut candidates = Vec::new();
+        while candidates.len() < 4 * threads && playouts < count * PLAYOUTS_PER_POSITION {
+            playouts += 1;
+            if let Some(moves) = random_playout(&mut rng, played_moves)
+                && seen.insert(from_moves::<AdvanceBitPosition>(&moves).key())
+            {
+                candidates.push(moves);
+            }
+        }
+        let positions: Vec<&str> = candidates.iter().map(String::as_str).collect();
+        let solutions = solve_batch(solver_name, &positions, &options, threads)
+            .expect("the solver name is validated");
+        for (moves, solution) in candidates.iter().zip(solutions) {
+            let plies_to_end = Interpretation::new(solution.score, played_moves, SolveMode::Strong)
+                .plies_to_end
+                .expect("strong scores tell when the game ends");
+            if remaining.contains(&plies_to_end) && found.len() < count {
+                found.push((moves.clone(), solution.score, plies_to_end));
+            }
+        }
+    }
+
+    write_dataset(args.value("--out"), &found, played_moves)?;
+    if found.len() < count {
+        return Err(format!(
+            "only {} of {} positions found in {} playouts",
+            found.len(),
+            count,
+            playouts
+        ));
+    }
+    Ok(())
+}
+
+// the bucket files the positions can end up in are checked before anything is solved
+fn check_out_dir(
+    dir: &str,
+    played_moves: usize,
+    remaining: &RangeInclusive<usize>,
+) -> Result<(), String> {
+    let max_remaining = WIDTH * HEIGHT - played_moves;
+    for plies_to_end in *remaining.start()..=(*remaining.end()).min(max_remaining) {
+        let path = std::path::Path::new(dir).join(bucket_name(played_moves, plies_to_end));
+        if path.exists() {
+            return Err(format!(
+                "{} already exists, the datasets aren't overwritten",
+                path.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+// "moves score" lines on stdout, or a new file per bucket in the --out directory
+fn write_dataset(
+    out: Option<&str>,
+    found: &[(String, i32, usize)],
+    played_moves: usize,
+) -> Result<(), String> {
+    let mut buckets: Vec<(String, String)> = Vec::new();
+    for (moves, score, plies_to_end) in found {
+        let name = bucket_name(played_moves, *plies_to_end);
+        let line = format!("{} {}\n", moves, score);
+        match buckets.iter_mut().find(|(bucket, _)| *bucket == name) {
+            Some((_, lines)) => lines.push_str(&line),
+            None => buckets.push((name, line)),
+        }
+    }
+    buckets.sort();
+
+    for (name, lines) in &buckets {
+        eprintln!("{} {}", name, lines.lines().count());
+        match out {
+            Some(dir) => {
+                let path = std::path::Path::new(dir).join(name);
+                std::fs::File::create_new(&path)
+                    .and_then(|mut file| file.write_all(lines.as_bytes()))
+                    .map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+            }
+            None => print!("{}", lines),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::time::Instant;
+
+use project::{
+    perft::{perft, perft_unique},
+    positions::{
+        GameOutcome, HEIGHT, Position, WIDTH, advance_bit_position::AdvanceBitPosition,
+        array_position::ArrayPosition, bit_position::BitPosition,
+    },
+    solvers::registry::PositionKind,
+};
+
+use crate::{cli::Args, commands::load_position};
+
+const REPRESENTATIONS: [(&str, PositionKind); 3] = [
+    ("array", PositionKind::Array),
+    ("bit", PositionKind::Bit),
+    ("advance-bit", PositionKind::AdvanceBit),
+];
+
+// the count and the time in microseconds
+fn count<P: Position + Default>(
+    encoded_position: &str,
+    depth: usize,
+    unique: bool,
+) -> Result<(u64, usize), String> {
+    let mut position = P::default();
+    if load_position(encoded_position, &mut position)? != GameOutcome::Ongoing {
+        return Err("the game is already over".to_string());
+    }
+
+    let start = Instant::now();
+    let count = if unique {
+        perft_unique(&mut position, depth)
+    } else {
+        perft(&mut position, depth)
+    };
+    Ok((count, start.elapsed().as_micros() as usize))
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let encoded_position = args.value("--position").unwrap_or("");
+    // validated before the depth, which can't be more than the free cells
+    let mut position = ArrayPosition::new();
+    if load_position(encoded_position, &mut position)? != GameOutcome::Ongoing {
+        return Err("the game is already over".to_string());
+    }
+    let max_depth = WIDTH * HEIGHT - position.played_moves();
+    let depth: usize = args
+        .value("--depth")
+        .ok_or("missing --depth")?
+        .parse()
+        .ok()
+        .filter(|depth| (1..=max_depth).contains(depth))
+        .ok_or_else(|| format!("--depth must be a number from 1 to {}", max_depth))?;
+    let unique = args.flag("--unique");
+
+    let representations: Vec<_> = match args.value("--representation") {
+        None => REPRESENTATIONS.to_vec(),
+        Some(name) => REPRESENTATIONS
+            .iter()
+            .filter(|(representation, _)| *representation == name)
+            .copied()
+            .collect(),
+    };
+    if representations.is_empty() {
+        return Err("--representation must be array, bit or advance-bit".to_string());
+    }
+
+    println!(
+        "{:<20} {:>5} {:>14} {:>12}",
+        "position",
+        "depth",
+        if unique { "positions" } else { "sequences" },
+        "time_us"
+    );
+    for depth in 1..=depth {
+        let mut counts = Vec::new();
+        for &(_, kind) in &representations {
+            let (count, time) = match kind {
+                PositionKind::Array => count::<ArrayPosition>(encoded_position, depth, unique)?,
+                PositionKind::Bit => count::<BitPosition>(encoded_position, depth, unique)?,
+                PositionKind::AdvanceBit => {
+                    count::<AdvanceBitPosition>(encoded_position, depth, unique)?
+                }
+            };
+            println!("{:<20} {:>5} {:>14} {:>12}", kind, depth, count, time);
+            counts.push(count);
+        }
+        if counts.iter().any(|&count| count != counts[0]) {
+            return Err(format!("the representations disagree at depth {}", depth));
+        }
+    }
+    Ok(())
+}
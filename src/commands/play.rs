@@ -0,0 +1,163 @@
+use std::io::{BufRead, Write};
+
+use project::{
+    analysis::ColumnScorer,
+    player::{Level, Player},
+    positions::{
+        GameOutcome, HEIGHT, Position, WIDTH, array_position::ArrayPosition,
+        position_history::PositionHistory,
+    },
+    score::Interpretation,
+    solvers::registry::find_solver,
+};
+
+use crate::{
+    cli::Args,
+    commands::{TRANSPOSITION_TABLE_SIZE, load_position, solver_name},
+};
+
+enum Input {
+    Column(usize),
+    Undo,
+    Quit,
+}
+
+fn read_input(position: &PositionHistory<ArrayPosition>) -> Result<Input, String> {
+    let stdin = std::io::stdin();
+    loop {
+        print!("your move (1-{}, undo, quit): ", WIDTH);
+        std::io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).map_err(|err| err.to_string())? == 0 {
+            return Ok(Input::Quit);
+        }
+        match line.trim() {
+            "undo" => return Ok(Input::Undo),
+            "quit" => return Ok(Input::Quit),
+            other => match other.parse::<usize>() {
+                Ok(colm) if (1..=WIDTH).contains(&colm) && position.can_play(colm - 1) => {
+                    return Ok(Input::Column(colm - 1));
+                }
+                _ => println!("'{}' is not a playable column", other),
+            },
+        }
+    }
+}
+
+fn play(position: &mut PositionHistory<ArrayPosition>, colm: usize) -> GameOutcome {
+    let player = 1 + (position.position().played_moves() & 1);
+    let is_winning = position.is_winning(colm);
+    position.play(colm);
+    if is_winning {
+        GameOutcome::Win(player)
+    } else if position.position().played_moves() == WIDTH * HEIGHT {
+        GameOutcome::Draw
+    } else {
+        GameOutcome::Ongoing
+    }
+}
+
+fn read_seed(args: &Args) -> Result<u64, String> {
+    match args.value("--seed") {
+        Some(seed) => seed
+            .parse()
+            .map_err(|_| format!("--seed must be a number, got '{}'", seed)),
+        None => Ok(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|err| err.to_string())?
+            .as_nanos() as u64),
+    }
+}
+
+// the variant of the solver with the strength the level needs, the solver itself when it has a
+// single strength
+fn level_solver(solver_name: &str, level: Level) -> String {
+    let (entry, _) = find_solver(solver_name).expect("the solver name is validated");
+    if entry.strengths.contains(&level.strength()) {
+        entry.variant_name(level.strength())
+    } else {
+        solver_name.to_string()
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let level = match args.value("--level") {
+        Some(name) => Level::from_name(name).ok_or_else(|| format!("unknown level '{}'", name))?,
+        None => Level::Perfect,
+    };
+    // a single solver for the whole game, its transposition table carries over between moves
+    let mut scorer = ColumnScorer::new(
+        &level_solver(solver_name(args)?, level),
+        TRANSPOSITION_TABLE_SIZE,
+    )?;
+    let seed = read_seed(args)?;
+    let mut opponent = Player::with_level(level, seed);
+    if level != Level::Perfect {
+        // printed so that a game can be replayed with --seed
+        println!("level {} with seed {}", level.name(), seed);
+    }
+
+    let mut position = PositionHistory::new(ArrayPosition::new());
+    let mut outcome = load_position(args.value("--position").unwrap_or(""), &mut position)?;
+    // the moves of --position weren't played in this game, undo stops at them
+    let starting_moves = position.history().len();
+    // 1 or 2, the same numbering as GameOutcome::Win
+    let next_player = 1 + (position.position().played_moves() & 1);
+    let solver_player = if args.flag("--second") {
+        next_player
+    } else {
+        3 - next_player
+    };
+
+    loop {
+        println!("{}\n", position);
+        match outcome {
+            GameOutcome::Win(player) if player == solver_player => {
+                println!("the solver won");
+                return Ok(());
+            }
+            GameOutcome::Win(_) => {
+                println!("you won");
+                return Ok(());
+            }
+            GameOutcome::Draw => {
+                println!("draw");
+                return Ok(());
+            }
+            GameOutcome::Ongoing => {}
+        }
+
+        let player = 1 + (position.position().played_moves() & 1);
+        if player == solver_player {
+            let scores = scorer.scores(&position.to_move_string())?;
+            let colm = opponent
+                .choose(&scores)
+                .expect("an ongoing game has a playable column");
+            let score = scores[colm].expect("the best column is playable");
+            let played_moves = position.position().played_moves();
+            println!(
+                "the solver plays {} ({})",
+                colm + 1,
+                Interpretation::new(score, played_moves, scorer.mode())
+            );
+            outcome = play(&mut position, colm);
+            continue;
+        }
+
+        match read_input(&position)? {
+            Input::Column(colm) => outcome = play(&mut position, colm),
+            Input::Undo => {
+                // take back the last move of the solver and the last one of the player
+                if position.history().len() < starting_moves + 2 {
+                    println!("nothing to undo");
+                    continue;
+                }
+                for _ in 0..2 {
+                    position.undo();
+                }
+            }
+            Input::Quit => return Ok(()),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use project::record::GameRecord;
+
+use crate::{cli::Args, commands::read_position};
+
+// the options that set a header of the record
+const HEADER_OPTIONS: [(&str, &str); 4] = [
+    ("--player1", "Player1"),
+    ("--player2", "Player2"),
+    ("--date", "Date"),
+    ("--time-control", "TimeControl"),
+];
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let mut record = match args.value("--in") {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .map_err(|err| format!("couldn't read {}: {}", path, err))?;
+            GameRecord::parse(&text).map_err(|err| format!("{}: {}", path, err))?
+        }
+        None => GameRecord::from_moves(&read_position(args)?)?,
+    };
+    for (option, header) in HEADER_OPTIONS {
+        if let Some(value) = args.value(option) {
+            record.set_header(header, value);
+        }
+    }
+
+    let output = if args.flag("--moves") {
+        format!("{}\n", record.to_moves())
+    } else {
+        record.to_string()
+    };
+    match args.value("--out") {
+        Some(path) => {
+            std::fs::write(path, output).map_err(|err| format!("couldn't write {}: {}", path, err))
+        }
+        None => {
+            print!("{}", output);
+            Ok(())
+        }
+    }
+}
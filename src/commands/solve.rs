@@ -0,0 +1,71 @@
+use project::{
+    positions::{GameOutcome, array_position::ArrayPosition},
+    score::Interpretation,
+    solvers::{SolveMode, Solver, finished_game_score, registry::build_solver_with},
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, load_position, read_position, solver_name},
+};
+
+pub struct Metric {
+    pub score: i32,
+    pub explored_nodes: usize,
+    pub time_in_microseconds: usize,
+}
+
+pub fn measure(solver: &mut dyn Solver, mode: SolveMode) -> Metric {
+    let start = std::time::Instant::now();
+    let score = solver.solve(mode);
+    let explored_nodes = solver.explored_nodes();
+    let time_in_microseconds = start.elapsed().as_micros() as usize;
+    Metric {
+        score,
+        explored_nodes,
+        time_in_microseconds,
+    }
+}
+
+fn print_metric(metric: &Metric) {
+    println!(
+        "{} {} {}",
+        metric.score, metric.explored_nodes, metric.time_in_microseconds
+    )
+}
+
+fn print_outcome(outcome: GameOutcome) {
+    match outcome {
+        GameOutcome::Win(player) => println!("player {} won", player),
+        GameOutcome::Draw => println!("draw"),
+        GameOutcome::Ongoing => {}
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let encoded_position = read_position(args)?;
+
+    let outcome = load_position(&encoded_position, &mut ArrayPosition::new())?;
+    if let Some(score) = finished_game_score(outcome, encoded_position.len()) {
+        // nothing to search, the game is already over
+        print_metric(&Metric {
+            score,
+            explored_nodes: 0,
+            time_in_microseconds: 0,
+        });
+        print_outcome(outcome);
+        return Ok(());
+    }
+
+    let options = build_options(args)?;
+    let (mut solver, mode) = build_solver_with(solver_name, &encoded_position, &options)
+        .expect("the solver name is validated");
+    let metric = measure(solver.as_mut(), mode);
+    print_metric(&metric);
+    println!(
+        "{}",
+        Interpretation::new(metric.score, encoded_position.len(), mode)
+    );
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use project::{
+    positions::{GameOutcome, HEIGHT, WIDTH, advance_bit_position::AdvanceBitPosition},
+    tablebase::Tablebase,
+};
+
+use crate::{cli::Args, commands::load_position};
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let stones: usize = args
+        .value("--stones")
+        .ok_or("missing --stones")?
+        .parse()
+        .map_err(|_| format!("--stones must be a number from 0 to {}", WIDTH * HEIGHT))?;
+    if stones > WIDTH * HEIGHT {
+        return Err(format!(
+            "--stones must be a number from 0 to {}",
+            WIDTH * HEIGHT
+        ));
+    }
+    let path = args.value("--out").ok_or("missing --out")?;
+
+    let encoded_position = args.value("--position").unwrap_or("");
+    let mut position = AdvanceBitPosition::new();
+    if load_position(encoded_position, &mut position)? != GameOutcome::Ongoing {
+        return Err("the game is already over".to_string());
+    }
+
+    let tablebase = Tablebase::build(position, stones);
+    tablebase
+        .save(path)
+        .map_err(|err| format!("couldn't write {}: {}", path, err))?;
+    println!(
+        "{} positions with at least {} stones written to {}",
+        tablebase.len(),
+        stones,
+        path
+    );
+    Ok(())
+}
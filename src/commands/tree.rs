@@ -0,0 +1,64 @@
+use project::{
+    positions::{GameOutcome, array_position::ArrayPosition},
+    score::Interpretation,
+    solvers::registry::build_solver_with,
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, load_position, read_position, solver_name},
+};
+
+const DEFAULT_DEPTH: usize = 2;
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let encoded_position = read_position(args)?;
+    if load_position(&encoded_position, &mut ArrayPosition::new())? != GameOutcome::Ongoing {
+        return Err("the game is already over".to_string());
+    }
+    let depth = match args.value("--depth") {
+        Some(depth) => depth
+            .parse()
+            .map_err(|_| format!("--depth must be a number, got '{}'", depth))?,
+        None => DEFAULT_DEPTH,
+    };
+    let format = args.value("--format").unwrap_or("dot");
+    if format != "dot" && format != "json" {
+        return Err(format!("unknown format '{}', dot or json", format));
+    }
+
+    let mut options = build_options(args)?;
+    options.search_tree_depth = Some(depth);
+    let (mut solver, mode) = build_solver_with(solver_name, &encoded_position, &options)
+        .expect("the solver name is validated");
+    let score = solver.solve(mode);
+    let tree = solver
+        .search_tree()
+        .ok_or_else(|| format!("{} doesn't record its search tree", solver_name))?
+        .clone()
+        .with_result(
+            score,
+            Interpretation::new(score, encoded_position.len(), mode),
+        );
+    let output = match format {
+        "json" => tree.to_json(),
+        _ => tree.to_dot(),
+    };
+
+    match args.value("--out") {
+        Some(path) => {
+            std::fs::write(path, output)
+                .map_err(|err| format!("couldn't write {}: {}", path, err))?;
+            println!(
+                "score {}, {} explored nodes, {} of them written to {}",
+                score,
+                solver.explored_nodes(),
+                tree.nodes().len(),
+                path
+            );
+        }
+        None => print!("{}", output),
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use project::{
+    batch::solve_batch,
+    dataset::parse_line,
+    solvers::registry::{Strength, find_solver},
+};
+
+use crate::{
+    cli::Args,
+    commands::{build_options, read_threads, solver_name},
+};
+
+/*
+    checks every line of the datasets and solves the valid ones again, the invalid lines and
+    the positions whose score differs are printed with their line number, fails if there is any
+*/
+pub fn run(args: &Args) -> Result<(), String> {
+    let solver_name = solver_name(args)?;
+    let (_, strength) = find_solver(solver_name).expect("the solver name is validated");
+    let datasets = args.values("--dataset");
+    if datasets.is_empty() {
+        return Err("missing --dataset".to_string());
+    }
+    let threads = read_threads(args)?;
+    let options = build_options(args)?;
+
+    let mut failures = 0;
+    for path in datasets {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read {}: {}", path, err))?;
+        let mut invalid = 0;
+        let mut labelled = Vec::new();
+        for (ind, line) in content.lines().enumerate() {
+            match parse_line(line) {
+                Ok(Some((moves, score))) => labelled.push((ind + 1, moves, score)),
+                Ok(None) => {}
+                Err(err) => {
+                    println!("{}:{}: {}", path, ind + 1, err);
+                    invalid += 1;
+                }
+            }
+        }
+
+        let positions: Vec<&str> = labelled.iter().map(|&(_, moves, _)| moves).collect();
+        let solutions = solve_batch(solver_name, &positions, &options, threads)
+            .expect("the solver name is validated");
+        let mut mismatches = 0;
+        for (&(line, moves, expected), solution) in labelled.iter().zip(&solutions) {
+            // weak solvers only have to find out who wins
+            let agrees = match strength {
+                Strength::Weak => solution.score.signum() == expected.signum(),
+                Strength::Strong => solution.score == expected,
+            };
+            if !agrees {
+                println!(
+                    "{}:{}: {} is labelled {} but {} finds {}",
+                    path, line, moves, expected, solver_name, solution.score
+                );
+                mismatches += 1;
+            }
+        }
+        eprintln!(
+            "{}: {} positions, {} invalid lines, {} mismatches",
+            path,
+            labelled.len(),
+            invalid,
+            mismatches
+        );
+        failures += invalid + mismatches;
+    }
+
+    if failures > 0 {
+        return Err(format!("{} lines failed the verification", failures));
+    }
+    Ok(())
+}
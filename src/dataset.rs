@@ -0,0 +1,79 @@
+use crate::{
+    positions::{
+        HEIGHT, Position, WIDTH,
+        advance_bit_position::{AdvanceBitPosition, column_mask},
+        array_position::ArrayPosition,
+        try_load_starting_position,
+    },
+    random::Rng,
+    solvers::{MAX_SCORE, MIN_SCORE},
+};
+
+/*
+    the buckets of the Test_Lx_Ry datasets, see the README
+    L1 up to 14 played moves, L2 up to 28, L3 more
+    R1 less than 14 remaining moves under perfect play, R2 less than 28, R3 more
+*/
+pub fn bucket(played_moves: usize, remaining_moves: usize) -> (usize, usize) {
+    let level = match played_moves {
+        0..=14 => 1,
+        15..=28 => 2,
+        _ => 3,
+    };
+    let rating = match remaining_moves {
+        0..14 => 1,
+        14..28 => 2,
+        _ => 3,
+    };
+    (level, rating)
+}
+
+pub fn bucket_name(played_moves: usize, remaining_moves: usize) -> String {
+    let (level, rating) = bucket(played_moves, remaining_moves);
+    format!("Test_L{}_R{}", level, rating)
+}
+
+/*
+    a game of played_moves random moves that don't lose at once, so nobody has won and the player
+    to move can't win with the next move either, None if a playout can't avoid it
+*/
+pub fn random_playout(rng: &mut Rng, played_moves: usize) -> Option<String> {
+    let mut position = AdvanceBitPosition::new();
+    let mut moves = String::with_capacity(played_moves);
+    for _ in 0..played_moves.min(WIDTH * HEIGHT) {
+        if position.can_win_next() {
+            return None;
+        }
+        let non_losing = position.possible_non_losing_moves();
+        let colms: Vec<usize> = (0..WIDTH)
+            .filter(|&colm| non_losing & column_mask(colm) != 0)
+            .collect();
+        if colms.is_empty() {
+            return None;
+        }
+        let colm = colms[rng.below(colms.len())];
+        position.play(colm);
+        moves.push(char::from(b'1' + colm as u8));
+    }
+    if position.can_win_next() {
+        return None;
+    }
+    Some(moves)
+}
+
+// a "moves score" line of a dataset, None for an empty line
+pub fn parse_line(line: &str) -> Result<Option<(&str, i32)>, String> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let (moves, score) = match parts[..] {
+        [] => return Ok(None),
+        [moves, score] => (moves, score),
+        _ => return Err("a line is \"moves score\"".to_string()),
+    };
+    try_load_starting_position(moves, &mut ArrayPosition::new())?;
+    let score = score
+        .parse()
+        .ok()
+        .filter(|score| (MIN_SCORE..=MAX_SCORE).contains(score))
+        .ok_or_else(|| format!("'{}' is not a score", score))?;
+    Ok(Some((moves, score)))
+}
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use crate::positions::{Position, WIDTH, advance_bit_position::AdvanceBitPosition};
+
+// a position together with the moves reaching it, so it can be written out and loaded again
+#[derive(Clone)]
+pub struct EnumeratedPosition {
+    pub moves: String,
+    pub position: AdvanceBitPosition,
+}
+
+/*
+    every legal position reachable from the root, ply by ply, positions reached by several
+    move orders (and with symmetric also mirror images) are kept once, with the first moves found
+    won positions are counted but not expanded, the game is over
+    visit gets the positions ply by ply, counts[n] is the number of positions n moves after the root
+*/
+pub fn enumerate_positions(
+    root: EnumeratedPosition,
+    max_ply: usize,
+    symmetric: bool,
+    mut visit: impl FnMut(&EnumeratedPosition),
+) -> Vec<usize> {
+    let key = |position: &AdvanceBitPosition| {
+        if symmetric {
+            position.symmetric_key()
+        } else {
+            position.key()
+        }
+    };
+
+    let root_ply = root.position.played_moves();
+    let mut counts = vec![1];
+    visit(&root);
+    let mut layer = vec![(root, false)];
+    for _ in root_ply..max_ply {
+        let mut seen = HashSet::new();
+        let mut next_layer = Vec::new();
+        for (parent, is_won) in &layer {
+            if *is_won {
+                continue;
+            }
+            for colm in 0..WIDTH {
+                if !parent.position.can_play(colm) {
+                    continue;
+                }
+                let mut position = parent.position;
+                let is_winning = position.is_winning(colm);
+                position.play(colm);
+                if seen.insert(key(&position)) {
+                    let child = EnumeratedPosition {
+                        moves: format!("{}{}", parent.moves, colm + 1),
+                        position,
+                    };
+                    visit(&child);
+                    next_layer.push((child, is_winning));
+                }
+            }
+        }
+        if next_layer.is_empty() {
+            break;
+        }
+        counts.push(next_layer.len());
+        layer = next_layer;
+    }
+    counts
+}
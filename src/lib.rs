@@ -0,0 +1,16 @@
+pub mod annotation;
+pub mod batch;
+pub mod analysis;
+pub mod dataset;
+pub mod enumeration;
+pub mod move_sorter;
+pub mod perft;
+pub mod player;
+pub mod positions;
+pub mod random;
+pub mod record;
+pub mod score;
+pub mod search_tree;
+pub mod solvers;
+pub mod tablebase;
+pub mod transposition_table;
@@ -1,5 +1,6 @@
-use crate::positions::WIDTH;
+use crate::positions::{HEIGHT, WIDTH};
 
+#[derive(Default)]
 pub struct MoveSorter {
     entries: [Entry; WIDTH],
     size: usize,
@@ -43,3 +44,80 @@ impl Iterator for MoveSorter {
         }
     }
 }
+
+// move ordering heuristics consulted on top of the number of created threats
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Heuristics {
+    // moves that caused a beta cutoff at the same ply
+    pub killer_moves: bool,
+    // cells that caused many deep beta cutoffs anywhere in the tree
+    pub history: bool,
+    // the best move stored in the transposition table by an earlier search goes first
+    pub hash_move: bool,
+}
+
+// the history counters are halved once one of them reaches the limit
+const HISTORY_LIMIT: u32 = 1 << 16;
+
+pub struct MoveOrdering {
+    heuristics: Heuristics,
+    // two killer moves for each number of played moves
+    killers: [[u64; 2]; WIDTH * HEIGHT],
+    // counters for each player and cell of the bitboard
+    history: [[u32; 64]; 2],
+}
+
+impl MoveOrdering {
+    pub fn new(heuristics: Heuristics) -> Self {
+        Self {
+            heuristics,
+            killers: [[0; 2]; WIDTH * HEIGHT],
+            history: [[0; 64]; 2],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.killers = [[0; 2]; WIDTH * HEIGHT];
+        self.history = [[0; 64]; 2];
+    }
+
+    // the threats stay the main criterion, killer moves and history only break the ties
+    pub fn score(&self, threats: i32, mov: u64, played_moves: usize) -> i32 {
+        let mut score = threats << 20;
+        if self.heuristics.killer_moves {
+            let killers = self.killers[played_moves];
+            if killers[0] == mov {
+                score |= 2 << 17;
+            } else if killers[1] == mov {
+                score |= 1 << 17;
+            }
+        }
+        if self.heuristics.history {
+            score |= self.history[played_moves & 1][mov.trailing_zeros() as usize] as i32;
+        }
+        score
+    }
+
+    pub fn uses_hash_move(&self) -> bool {
+        self.heuristics.hash_move
+    }
+
+    pub fn cutoff(&mut self, mov: u64, played_moves: usize) {
+        if self.heuristics.killer_moves {
+            let killers = &mut self.killers[played_moves];
+            if killers[0] != mov {
+                killers[1] = killers[0];
+                killers[0] = mov;
+            }
+        }
+        if self.heuristics.history {
+            let remaining = (WIDTH * HEIGHT - played_moves) as u32;
+            let counters = &mut self.history[played_moves & 1];
+            let counter = &mut counters[mov.trailing_zeros() as usize];
+            *counter += remaining * remaining;
+            if *counter >= HISTORY_LIMIT {
+                counters.iter_mut().for_each(|counter| *counter /= 2);
+            }
+        }
+    }
+}
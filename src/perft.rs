@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use crate::positions::{Position, WIDTH, bitboards};
+
+/*
+    counts of the move sequences of a given length, a winning move ends the sequence
+    the counts don't depend on the position representation, so they validate the move generation
+*/
+
+// number of move sequences of exactly depth moves
+pub fn perft<P: Position>(position: &mut P, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut count = 0;
+    for colm in 0..WIDTH {
+        if !position.can_play(colm) {
+            continue;
+        }
+        if depth == 1 {
+            count += 1;
+        } else if !position.is_winning(colm) {
+            position.play(colm);
+            count += perft(position, depth - 1);
+            position.reverse_play(colm);
+        }
+    }
+    count
+}
+
+// number of distinct positions reached by those sequences, the same key as the bit positions
+pub fn perft_unique<P: Position>(position: &mut P, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    // indexed by the remaining depth
+    let mut visited = vec![HashSet::new(); depth + 1];
+    collect(position, depth, &mut visited);
+    visited[0].len() as u64
+}
+
+fn key<P: Position>(position: &P) -> u64 {
+    let (current, mask) = bitboards(position);
+    current + mask
+}
+
+fn collect<P: Position>(position: &mut P, depth: usize, visited: &mut [HashSet<u64>]) {
+    // a position already seen with the same remaining depth reaches the same positions
+    if !visited[depth].insert(key(position)) {
+        return;
+    }
+
+    for colm in 0..WIDTH {
+        if !position.can_play(colm) {
+            continue;
+        }
+        if depth == 1 {
+            position.play(colm);
+            visited[0].insert(key(position));
+            position.reverse_play(colm);
+        } else if !position.is_winning(colm) {
+            position.play(colm);
+            collect(position, depth - 1, visited);
+            position.reverse_play(colm);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{analysis::best_column, positions::WIDTH, random::Rng, solvers::registry::Strength};
+
+// how a move is picked from the scores of the columns
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Style {
+    // the best column, ties are broken in favour of the center
+    Perfect,
+    // any column that doesn't lose, any column when all of them lose
+    RandomNonLosing,
+    // columns are picked with probability proportional to exp(score / temperature)
+    Softmax { temperature: f64 },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Level {
+    Easy,
+    Medium,
+    Hard,
+    Perfect,
+}
+
+impl Level {
+    pub const ALL: [Level; 4] = [Level::Easy, Level::Medium, Level::Hard, Level::Perfect];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Easy => "easy",
+            Level::Medium => "medium",
+            Level::Hard => "hard",
+            Level::Perfect => "perfect",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.name() == name)
+    }
+
+    // the style and the blunder rate of the level
+    fn settings(self) -> (Style, f64) {
+        match self {
+            Level::Easy => (Style::RandomNonLosing, 0.25),
+            // the temperatures are meant for the -1, 0 and 1 scores of a weak solver
+            Level::Medium => (Style::Softmax { temperature: 0.5 }, 0.1),
+            Level::Hard => (Style::Softmax { temperature: 0.2 }, 0.02),
+            Level::Perfect => (Style::Perfect, 0.0),
+        }
+    }
+
+    // only perfect needs the exact scores, the other levels are happy with who wins
+    pub fn strength(self) -> Strength {
+        match self {
+            Level::Perfect => Strength::Strong,
+            _ => Strength::Weak,
+        }
+    }
+}
+
+/*
+    picks moves from the column scores of a solver, with probability blunder_rate
+    the move is a random playable column whatever the scores are
+*/
+pub struct Player {
+    style: Style,
+    blunder_rate: f64,
+    rng: Rng,
+}
+
+impl Player {
+    pub fn new(style: Style, blunder_rate: f64, seed: u64) -> Self {
+        Self {
+            style,
+            blunder_rate,
+            rng: Rng::new(seed),
+        }
+    }
+
+    pub fn with_level(level: Level, seed: u64) -> Self {
+        let (style, blunder_rate) = level.settings();
+        Self::new(style, blunder_rate, seed)
+    }
+
+    // None when no column is playable
+    pub fn choose(&mut self, scores: &[Option<i32>; WIDTH]) -> Option<usize> {
+        let playable: Vec<(usize, i32)> = scores
+            .iter()
+            .enumerate()
+            .filter_map(|(colm, score)| score.map(|score| (colm, score)))
+            .collect();
+        if playable.is_empty() {
+            return None;
+        }
+
+        if self.blunder_rate > 0.0 && self.rng.next_f64() < self.blunder_rate {
+            return Some(playable[self.rng.below(playable.len())].0);
+        }
+
+        match self.style {
+            Style::Perfect => best_column(scores),
+            Style::RandomNonLosing => {
+                let non_losing: Vec<usize> = playable
+                    .iter()
+                    .filter(|(_, score)| *score >= 0)
+                    .map(|&(colm, _)| colm)
+                    .collect();
+                if non_losing.is_empty() {
+                    Some(playable[self.rng.below(playable.len())].0)
+                } else {
+                    Some(non_losing[self.rng.below(non_losing.len())])
+                }
+            }
+            Style::Softmax { temperature } => {
+                // shifted by the best score so the exponentials don't overflow
+                let best = playable.iter().map(|&(_, score)| score).max().unwrap();
+                let weights: Vec<f64> = playable
+                    .iter()
+                    .map(|&(_, score)| ((score - best) as f64 / temperature).exp())
+                    .collect();
+                let mut target = self.rng.next_f64() * weights.iter().sum::<f64>();
+                for (&(colm, _), weight) in playable.iter().zip(&weights) {
+                    if target < *weight {
+                        return Some(colm);
+                    }
+                    target -= weight;
+                }
+                playable.last().map(|&(colm, _)| colm)
+            }
+        }
+    }
+}
@@ -5,18 +5,81 @@ pub trait Position {
     fn can_play(&self, colm:usize) -> bool;
     fn play(&mut self, colm:usize);
     fn is_winning(&self, colm:usize) -> bool;
+    // undo the last move, which must have been played in colm
+    fn reverse_play(&mut self, colm:usize);
+    // 0 - empty cell, 1 - player 1, 2 - player 2, row 0 is the bottom of the board
+    fn cell(&self, colm:usize, row:usize) -> usize;
 }
 
-pub fn load_starting_position<P: Position>(encoded_position: &str, position: &mut P){
-    for ch in encoded_position.as_bytes() {
-        let colm = (ch - '1' as u8) as usize;
-        if colm >= WIDTH || !position.can_play(colm) || position.is_winning(colm) {
-            panic!("invalid starting position");
+// state of the game after the encoded moves are played
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameOutcome {
+    Ongoing,
+    // 1 - the player who moved first, 2 - the second player
+    Win(usize),
+    Draw,
+}
+
+pub fn load_starting_position<P: Position>(encoded_position: &str, position: &mut P) -> GameOutcome {
+    try_load_starting_position(encoded_position, position)
+        .unwrap_or_else(|err| panic!("invalid starting position: {}", err))
+}
+
+// a new position with the encoded moves played, panics on invalid moves
+pub fn from_moves<P: Position + Default>(encoded_position: &str) -> P {
+    let mut position = P::default();
+    load_starting_position(encoded_position, &mut position);
+    position
+}
+
+// same as load_starting_position, but tells which move is invalid instead of panicking
+pub fn try_load_starting_position<P: Position>(
+    encoded_position: &str,
+    position: &mut P,
+) -> Result<GameOutcome, String> {
+    let mut outcome = GameOutcome::Ongoing;
+    for (ind, ch) in encoded_position.chars().enumerate() {
+        let colm = match ch.to_digit(10) {
+            Some(digit @ 1..) if digit as usize <= WIDTH => digit as usize - 1,
+            _ => return Err(format!("move {}: '{}' is not a column", ind + 1, ch)),
+        };
+        // no moves are allowed after the game has been won
+        if outcome != GameOutcome::Ongoing {
+            return Err(format!("move {}: the game is already over", ind + 1));
+        }
+        if !position.can_play(colm) {
+            return Err(format!("move {}: column {} is full", ind + 1, colm + 1));
+        }
+        if position.is_winning(colm) {
+            outcome = GameOutcome::Win(1 + (ind & 1));
         }
         position.play(colm);
     }
+    if outcome == GameOutcome::Ongoing && encoded_position.len() == WIDTH * HEIGHT {
+        outcome = GameOutcome::Draw;
+    }
+    Ok(outcome)
+}
+
+// bitboards (current, mask) of any position in the layout used by the bit positions
+pub(crate) fn bitboards<P: Position>(position: &P) -> (u64, u64) {
+    // stones of each player, index 0 collects the empty cells
+    let mut stones = [0u64; 3];
+    for colm in 0..WIDTH {
+        for row in 0..HEIGHT {
+            stones[position.cell(colm, row)] |= 1u64 << (colm * (HEIGHT + 1) + row);
+        }
+    }
+    let mask = stones[1] | stones[2];
+    let current_player = 1 + (mask.count_ones() as usize & 1);
+    (stones[current_player], mask)
 }
 
 pub mod advance_bit_position;
 pub mod bit_position;
-pub mod array_position;
\ No newline at end of file
+pub mod array_position;
+pub mod position_history;
+mod render;
+
+#[cfg(test)]
+mod tests;
@@ -1,4 +1,9 @@
-use crate::positions::{HEIGHT, Position, WIDTH};
+use std::fmt;
+
+use crate::positions::{
+    HEIGHT, Position, WIDTH, array_position::ArrayPosition, bit_position::BitPosition,
+    bitboards, render::{write_bitboards, write_board},
+};
 
 // compile time function to help generate bottom_mask
 const fn bottom(width: usize, height: usize) -> u64 {
@@ -30,7 +35,7 @@ col 0  1  2
     mask is a bitboard with 1 where there is a non-empty cell
     current is a bitboard where the cells of the current player are marked as 1
 */
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct AdvanceBitPosition {
     current: u64,
     mask: u64,
@@ -54,15 +59,49 @@ impl AdvanceBitPosition {
         self.current + self.mask
     }
 
-    fn possible(&self) -> u64 {
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+
+    pub fn mask(&self) -> u64 {
+        self.mask
+    }
+
+    fn from_bitboards(current: u64, mask: u64) -> Self {
+        Self {
+            current,
+            mask,
+            played_moves: mask.count_ones() as usize,
+        }
+    }
+
+    // the position reflected left to right, the columns are swapped
+    pub fn mirror(&self) -> Self {
+        let mut current = 0;
+        let mut mask = 0;
+        for colm in 0..WIDTH {
+            let shift = (WIDTH - 1 - colm) * (HEIGHT + 1);
+            let from = colm * (HEIGHT + 1);
+            current |= ((self.current >> from) & column_mask(0)) << shift;
+            mask |= ((self.mask >> from) & column_mask(0)) << shift;
+        }
+        Self::from_bitboards(current, mask)
+    }
+
+    // the same key for a position and its mirror image
+    pub fn symmetric_key(&self) -> u64 {
+        self.key().min(self.mirror().key())
+    }
+
+    pub(crate) fn possible(&self) -> u64 {
         (self.mask + BOTTOM_MASK) & BOARD_MASK
     }
 
-    fn current_winning_moves(&self) -> u64 {
+    pub(crate) fn current_winning_moves(&self) -> u64 {
         compute_winning_position(self.current, self.mask)
     }
 
-    fn opponent_winning_moves(&self) -> u64 {
+    pub(crate) fn opponent_winning_moves(&self) -> u64 {
         compute_winning_position(self.current ^ self.mask, self.mask)
     }
 
@@ -89,8 +128,8 @@ impl AdvanceBitPosition {
     }
 
     pub fn play_move(&mut self, mov: u64) {
-        self.current = self.mask ^ self.current;
-        self.mask = self.mask | mov;
+        self.current ^= self.mask;
+        self.mask |= mov;
         self.played_moves+=1;
     }
 
@@ -105,26 +144,81 @@ impl Position for AdvanceBitPosition {
     }
 
     fn play(&mut self, colm: usize) {
-        self.current = self.mask ^ self.current;
+        self.current ^= self.mask;
         self.mask = self.mask | (self.mask + bottom_mask_colm(colm));
         self.played_moves += 1;
     }
 
+    fn reverse_play(&mut self, colm: usize) {
+        // the stones of a column are contiguous, so the top one is just under the first empty cell
+        let top = ((self.mask & column_mask(colm)) + bottom_mask_colm(colm)) >> 1;
+        self.mask ^= top;
+        self.current ^= self.mask;
+        self.played_moves -= 1;
+    }
+
+    fn cell(&self, colm: usize, row: usize) -> usize {
+        let bit = 1u64 << (colm * (HEIGHT + 1) + row);
+        let current_player = 1 + (self.played_moves & 1);
+        if self.mask & bit == 0 {
+            0
+        } else if self.current & bit != 0 {
+            current_player
+        } else {
+            3 - current_player
+        }
+    }
+
     fn is_winning(&self, colm: usize) -> bool {
         (self.current_winning_moves() & self.possible() & column_mask(colm)) != 0
     }
 }
 
+impl From<BitPosition> for AdvanceBitPosition {
+    fn from(position: BitPosition) -> Self {
+        Self::from_bitboards(position.current(), position.mask())
+    }
+}
+
+impl From<ArrayPosition> for AdvanceBitPosition {
+    fn from(position: ArrayPosition) -> Self {
+        let (current, mask) = bitboards(&position);
+        Self::from_bitboards(current, mask)
+    }
+}
+
+impl fmt::Display for AdvanceBitPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_board(f, self, None)
+    }
+}
+
+impl fmt::Debug for AdvanceBitPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "AdvanceBitPosition {{ current: {:#x}, mask: {:#x}, played_moves: {} }}",
+            self.current, self.mask, self.played_moves
+        )?;
+        write_bitboards(f, &[("current", self.current), ("mask", self.mask)])
+    }
+}
+
 fn top_mask_colm(colm: usize) -> u64 {
-    (1u64 << (HEIGHT - 1)) << colm * (HEIGHT + 1)
+    (1u64 << (HEIGHT - 1)) << (colm * (HEIGHT + 1))
 }
 
 fn bottom_mask_colm(colm: usize) -> u64 {
-    1u64 << colm * (HEIGHT + 1)
+    1u64 << (colm * (HEIGHT + 1))
+}
+
+// the column of a move bitboard with a single stone
+pub fn move_column(mov: u64) -> usize {
+    mov.trailing_zeros() as usize / (HEIGHT + 1)
 }
 
 pub fn column_mask(colm: usize) -> u64 {
-    ((1u64 << HEIGHT) - 1) << colm * (HEIGHT + 1)
+    ((1u64 << HEIGHT) - 1) << (colm * (HEIGHT + 1))
 }
 
 fn compute_winning_position(position: u64, mask: u64) -> u64 {
@@ -139,7 +233,7 @@ fn compute_winning_position(position: u64, mask: u64) -> u64 {
     // xx_x
     winning |= horizontal_pair & (position >> horizontal_shift);
     // _xx
-    horizontal_pair = horizontal_pair >> (3 * horizontal_shift);
+    horizontal_pair >>= 3 * horizontal_shift;
     // _xxx
     winning |= horizontal_pair & (position << horizontal_shift);
     // x_xx
@@ -150,7 +244,7 @@ fn compute_winning_position(position: u64, mask: u64) -> u64 {
         (position << main_dialonal_shift) & (position << (2 * main_dialonal_shift));
     winning |= main_dialonal_pair & (position << (3 * main_dialonal_shift));
     winning |= main_dialonal_pair & (position >> main_dialonal_shift);
-    main_dialonal_pair = main_dialonal_pair >> (3 * main_dialonal_shift);
+    main_dialonal_pair >>= 3 * main_dialonal_shift;
     winning |= main_dialonal_pair & (position << main_dialonal_shift);
     winning |= main_dialonal_pair & (position >> (3 * main_dialonal_shift));
 
@@ -159,11 +253,11 @@ fn compute_winning_position(position: u64, mask: u64) -> u64 {
         (position << off_dialonal_shift) & (position << (2 * off_dialonal_shift));
     winning |= off_dialonal_pair & (position << (3 * off_dialonal_shift));
     winning |= off_dialonal_pair & (position >> off_dialonal_shift);
-    off_dialonal_pair = off_dialonal_pair >> (3 * off_dialonal_shift);
+    off_dialonal_pair >>= 3 * off_dialonal_shift;
     winning |= off_dialonal_pair & (position << off_dialonal_shift);
     winning |= off_dialonal_pair & (position >> (3 * off_dialonal_shift));
 
-    return winning & (BOARD_MASK ^ mask);
+    winning & (BOARD_MASK ^ mask)
 }
 
 fn popcount(mut mask: u64) -> i32 {
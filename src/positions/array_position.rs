@@ -1,5 +1,11 @@
-use crate::positions::{HEIGHT, Position, WIDTH};
+use std::fmt;
 
+use crate::positions::{
+    HEIGHT, Position, WIDTH, advance_bit_position::AdvanceBitPosition,
+    bit_position::BitPosition, render::write_board,
+};
+
+#[derive(Clone, Default)]
 pub struct ArrayPosition {
     // 0 - empty cell, 1 - player 1, 2 - player 2
     board: [[i32; HEIGHT]; WIDTH],
@@ -15,11 +21,22 @@ impl ArrayPosition {
             played_moves: 0,
         }
     }
-    
-    pub fn reverse_play(&mut self, colm: usize) {
-        self.played_moves -= 1;
-        self.columns_heights[colm] -= 1;
-        self.board[colm][self.columns_heights[colm]] -= self.current_player();
+
+    // rebuilds the board from the cells of any other representation
+    fn from_cells<P: Position>(position: &P) -> Self {
+        let mut array_position = Self::new();
+        for colm in 0..WIDTH {
+            for row in 0..HEIGHT {
+                let cell = position.cell(colm, row);
+                if cell == 0 {
+                    break;
+                }
+                array_position.board[colm][row] = cell as i32;
+                array_position.columns_heights[colm] += 1;
+                array_position.played_moves += 1;
+            }
+        }
+        array_position
     }
 
     fn current_player(&self) -> i32 {
@@ -42,6 +59,16 @@ impl Position for ArrayPosition {
         self.played_moves += 1;
     }
 
+    fn reverse_play(&mut self, colm: usize) {
+        self.played_moves -= 1;
+        self.columns_heights[colm] -= 1;
+        self.board[colm][self.columns_heights[colm]] -= self.current_player();
+    }
+
+    fn cell(&self, colm: usize, row: usize) -> usize {
+        self.board[colm][row] as usize
+    }
+
 
     fn is_winning(&self, colm: usize) -> bool {
         let current_player = self.current_player();
@@ -77,8 +104,37 @@ impl Position for ArrayPosition {
                 return true;
             }
         }
-        return false;
+        false
+    }
+
+
+}
+
+impl From<BitPosition> for ArrayPosition {
+    fn from(position: BitPosition) -> Self {
+        Self::from_cells(&position)
     }
+}
 
+impl From<AdvanceBitPosition> for ArrayPosition {
+    fn from(position: AdvanceBitPosition) -> Self {
+        Self::from_cells(&position)
+    }
+}
 
+impl fmt::Display for ArrayPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_board(f, self, None)
+    }
+}
+
+impl fmt::Debug for ArrayPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "ArrayPosition {{ columns_heights: {:?}, played_moves: {} }}",
+            self.columns_heights, self.played_moves
+        )?;
+        write_board(f, self, None)
+    }
 }
@@ -1,4 +1,9 @@
-use crate::positions::{HEIGHT, Position};
+use std::fmt;
+
+use crate::positions::{
+    HEIGHT, Position, advance_bit_position::AdvanceBitPosition, array_position::ArrayPosition,
+    bitboards, render::{write_bitboards, write_board},
+};
 
 /*
     the board is encoded in this direction
@@ -17,7 +22,7 @@ col 0  1  2
     current is a bitboard where the cells of the current player are marked as 1
 */
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct BitPosition {
     current: u64,
     mask: u64,
@@ -40,6 +45,22 @@ impl BitPosition {
     pub fn key(&self) -> u64 {
         self.current + self.mask
     }
+
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+
+    pub fn mask(&self) -> u64 {
+        self.mask
+    }
+
+    fn from_bitboards(current: u64, mask: u64) -> Self {
+        Self {
+            current,
+            mask,
+            played_moves: mask.count_ones() as usize,
+        }
+    }
 }
 
 impl Position for BitPosition {
@@ -48,11 +69,31 @@ impl Position for BitPosition {
     }
 
     fn play(&mut self, colm: usize) {
-        self.current = self.mask ^ self.current;
+        self.current ^= self.mask;
         self.mask = self.mask | (self.mask + bottom_mask(colm));
         self.played_moves += 1;
     }
 
+    fn reverse_play(&mut self, colm: usize) {
+        // the stones of a column are contiguous, so the top one is just under the first empty cell
+        let top = ((self.mask & column_mask(colm)) + bottom_mask(colm)) >> 1;
+        self.mask ^= top;
+        self.current ^= self.mask;
+        self.played_moves -= 1;
+    }
+
+    fn cell(&self, colm: usize, row: usize) -> usize {
+        let bit = 1u64 << (colm * (HEIGHT + 1) + row);
+        let current_player = 1 + (self.played_moves & 1);
+        if self.mask & bit == 0 {
+            0
+        } else if self.current & bit != 0 {
+            current_player
+        } else {
+            3 - current_player
+        }
+    }
+
     fn is_winning(&self, colm: usize) -> bool {
         // filling top of the colm
         let position = self.current | ((self.mask + bottom_mask(colm)) & column_mask(colm));
@@ -74,21 +115,51 @@ impl Position for BitPosition {
         let off_diagonal_pair = position & (position >> off_diagonal_shift);
         let off_diagonal_line = off_diagonal_pair & (off_diagonal_pair >> (2 * off_diagonal_shift));
 
-        return vertical_line != 0
+        vertical_line != 0
             || horizontal_line != 0
             || main_diagonal_line != 0
-            || off_diagonal_line != 0;
+            || off_diagonal_line != 0
+    }
+}
+
+impl From<AdvanceBitPosition> for BitPosition {
+    fn from(position: AdvanceBitPosition) -> Self {
+        Self::from_bitboards(position.current(), position.mask())
+    }
+}
+
+impl From<ArrayPosition> for BitPosition {
+    fn from(position: ArrayPosition) -> Self {
+        let (current, mask) = bitboards(&position);
+        Self::from_bitboards(current, mask)
+    }
+}
+
+impl fmt::Display for BitPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_board(f, self, None)
+    }
+}
+
+impl fmt::Debug for BitPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "BitPosition {{ current: {:#x}, mask: {:#x}, played_moves: {} }}",
+            self.current, self.mask, self.played_moves
+        )?;
+        write_bitboards(f, &[("current", self.current), ("mask", self.mask)])
     }
 }
 
 fn top_mask(colm: usize) -> u64 {
-    (1u64 << (HEIGHT - 1)) << colm * (HEIGHT + 1)
+    (1u64 << (HEIGHT - 1)) << (colm * (HEIGHT + 1))
 }
 
 fn bottom_mask(colm: usize) -> u64 {
-    1u64 << colm * (HEIGHT + 1)
+    1u64 << (colm * (HEIGHT + 1))
 }
 
 fn column_mask(colm: usize) -> u64 {
-    ((1u64 << HEIGHT) - 1) << colm * (HEIGHT + 1)
+    ((1u64 << HEIGHT) - 1) << (colm * (HEIGHT + 1))
 }
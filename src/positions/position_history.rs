@@ -0,0 +1,85 @@
+use std::fmt;
+
+use crate::positions::{Position, render::write_board};
+
+/*
+    wraps any position and records the columns played on top of it,
+    so moves can be undone and the game can be written back as a move string
+*/
+#[derive(Clone)]
+pub struct PositionHistory<P: Position> {
+    position: P,
+    moves: Vec<usize>,
+}
+
+impl<P: Position> PositionHistory<P> {
+    pub fn new(position: P) -> Self {
+        Self {
+            position,
+            moves: Vec::new(),
+        }
+    }
+
+    pub fn position(&self) -> &P {
+        &self.position
+    }
+
+    pub fn into_position(self) -> P {
+        self.position
+    }
+
+    // columns played since the wrapper was created, 0-indexed
+    pub fn history(&self) -> &[usize] {
+        &self.moves
+    }
+
+    pub fn last_move(&self) -> Option<usize> {
+        self.moves.last().copied()
+    }
+
+    // returns the column of the undone move or None if there is nothing to undo
+    pub fn undo(&mut self) -> Option<usize> {
+        let colm = self.moves.pop()?;
+        self.position.reverse_play(colm);
+        Some(colm)
+    }
+
+    // the same 1-indexed encoding accepted by load_starting_position
+    pub fn to_move_string(&self) -> String {
+        self.moves
+            .iter()
+            .map(|&colm| char::from(b'1' + colm as u8))
+            .collect()
+    }
+}
+
+impl<P: Position> Position for PositionHistory<P> {
+    fn can_play(&self, colm: usize) -> bool {
+        self.position.can_play(colm)
+    }
+
+    fn play(&mut self, colm: usize) {
+        self.position.play(colm);
+        self.moves.push(colm);
+    }
+
+    fn is_winning(&self, colm: usize) -> bool {
+        self.position.is_winning(colm)
+    }
+
+    fn reverse_play(&mut self, colm: usize) {
+        let last = self.moves.pop();
+        debug_assert_eq!(last, Some(colm), "only the last move can be reversed");
+        self.position.reverse_play(colm);
+    }
+
+    fn cell(&self, colm: usize, row: usize) -> usize {
+        self.position.cell(colm, row)
+    }
+}
+
+impl<P: Position> fmt::Display for PositionHistory<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_board(f, &self.position, self.last_move())
+    }
+}
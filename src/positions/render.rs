@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::positions::{HEIGHT, Position, WIDTH};
+
+// indexed by Position::cell, X is the player who moves first
+const STONES: [char; 3] = ['.', 'X', 'O'];
+
+/*
+     . . . . . . .
+     . . . . . . .
+     . . . . . . .
+     . . . X . . .
+     . . . O . . .
+     . . X O . . .
+     1 2 3 4 5 6 7
+           ^
+    O to move, 4 played moves
+*/
+pub(crate) fn write_board<P: Position>(
+    f: &mut fmt::Formatter,
+    position: &P,
+    last_move: Option<usize>,
+) -> fmt::Result {
+    let mut played_moves = 0;
+    for row in (0..HEIGHT).rev() {
+        for colm in 0..WIDTH {
+            let cell = position.cell(colm, row);
+            if cell != 0 {
+                played_moves += 1;
+            }
+            write!(f, " {}", STONES[cell])?;
+        }
+        writeln!(f)?;
+    }
+    for colm in 0..WIDTH {
+        write!(f, " {}", colm + 1)?;
+    }
+    writeln!(f)?;
+    if let Some(colm) = last_move {
+        writeln!(f, "{:>width$}", '^', width = 2 * colm + 2)?;
+    }
+    write!(
+        f,
+        "{} to move, {} played moves",
+        STONES[1 + (played_moves & 1)],
+        played_moves
+    )
+}
+
+/*
+    bitboards side by side, including the extra top row of each column
+    current   mask
+    .......   .......
+    ...1...   ...1...
+*/
+pub(crate) fn write_bitboards(f: &mut fmt::Formatter, bitboards: &[(&str, u64)]) -> fmt::Result {
+    let header: Vec<String> = bitboards
+        .iter()
+        .map(|(name, _)| format!("{:<width$}", name, width = WIDTH))
+        .collect();
+    write!(f, "{}", header.join("   ").trim_end())?;
+    for row in (0..=HEIGHT).rev() {
+        let line: Vec<String> = bitboards
+            .iter()
+            .map(|(_, bitboard)| {
+                (0..WIDTH)
+                    .map(|colm| match (bitboard >> (colm * (HEIGHT + 1) + row)) & 1 {
+                        1 => '1',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        write!(f, "\n{}", line.join("   "))?;
+    }
+    Ok(())
+}
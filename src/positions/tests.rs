@@ -0,0 +1,171 @@
+use crate::{
+    positions::{
+        HEIGHT, Position, WIDTH,
+        advance_bit_position::{AdvanceBitPosition, column_mask},
+        array_position::ArrayPosition,
+        bit_position::BitPosition,
+        position_history::PositionHistory,
+    },
+    random::Rng,
+};
+
+const GAMES: usize = 3000;
+
+fn bit(colm: usize, row: usize) -> u64 {
+    1u64 << (colm * (HEIGHT + 1) + row)
+}
+
+fn player_cell(position: &ArrayPosition, colm: i32, row: i32, player: usize) -> bool {
+    colm >= 0
+        && colm < WIDTH as i32
+        && row >= 0
+        && row < HEIGHT as i32
+        && position.cell(colm as usize, row as usize) == player
+}
+
+// cells where a stone of player would complete four in a row, found by walking the board
+fn brute_force_threats(position: &ArrayPosition, player: usize) -> u64 {
+    let mut threats = 0;
+    for colm in 0..WIDTH {
+        for row in 0..HEIGHT {
+            if position.cell(colm, row) != 0 {
+                continue;
+            }
+            for (dx, dy) in [(1, 0), (0, 1), (1, 1), (1, -1)] {
+                let mut line = 1;
+                for sign in [-1, 1] {
+                    let (mut x, mut y) = (colm as i32 + sign * dx, row as i32 + sign * dy);
+                    while player_cell(position, x, y, player) {
+                        line += 1;
+                        x += sign * dx;
+                        y += sign * dy;
+                    }
+                }
+                if line >= 4 {
+                    threats |= bit(colm, row);
+                }
+            }
+        }
+    }
+    threats
+}
+
+fn check_agreement(
+    array_position: &ArrayPosition,
+    bit_position: &BitPosition,
+    advance_bit_position: &AdvanceBitPosition,
+) {
+    let played_moves = array_position.played_moves();
+    assert_eq!(bit_position.played_moves(), played_moves);
+    assert_eq!(advance_bit_position.played_moves(), played_moves);
+
+    for colm in 0..WIDTH {
+        for row in 0..HEIGHT {
+            let cell = array_position.cell(colm, row);
+            assert_eq!(bit_position.cell(colm, row), cell);
+            assert_eq!(advance_bit_position.cell(colm, row), cell);
+        }
+    }
+
+    assert_eq!(bit_position.key(), advance_bit_position.key());
+    assert_eq!(BitPosition::from(array_position.clone()).key(), bit_position.key());
+
+    for colm in 0..WIDTH {
+        let can_play = array_position.can_play(colm);
+        assert_eq!(bit_position.can_play(colm), can_play);
+        assert_eq!(advance_bit_position.can_play(colm), can_play);
+        if can_play {
+            let is_winning = array_position.is_winning(colm);
+            assert_eq!(bit_position.is_winning(colm), is_winning);
+            assert_eq!(advance_bit_position.is_winning(colm), is_winning);
+        }
+    }
+
+    let current_player = 1 + (played_moves & 1);
+    assert_eq!(
+        advance_bit_position.current_winning_moves(),
+        brute_force_threats(array_position, current_player)
+    );
+    assert_eq!(
+        advance_bit_position.opponent_winning_moves(),
+        brute_force_threats(array_position, 3 - current_player)
+    );
+}
+
+// possible_non_losing_moves is only used when the player to move cannot win immediately
+fn check_non_losing_moves(position: &AdvanceBitPosition) {
+    let non_losing = position.possible_non_losing_moves();
+    for colm in 0..WIDTH {
+        if !position.can_play(colm) {
+            continue;
+        }
+        let mut next = *position;
+        next.play(colm);
+        let opponent_wins =
+            (0..WIDTH).any(|reply| next.can_play(reply) && next.is_winning(reply));
+        let kept = non_losing & column_mask(colm) != 0;
+        assert_eq!(kept, !opponent_wins, "column {} of\n{:?}", colm, position);
+    }
+}
+
+#[test]
+fn representations_agree_on_random_games() {
+    let mut rng = Rng::new(0x5EED);
+    for _ in 0..GAMES {
+        let mut array_position = PositionHistory::new(ArrayPosition::new());
+        let mut bit_position = BitPosition::new();
+        let mut advance_bit_position = AdvanceBitPosition::new();
+
+        loop {
+            check_agreement(array_position.position(), &bit_position, &advance_bit_position);
+            if !advance_bit_position.can_win_next() {
+                check_non_losing_moves(&advance_bit_position);
+            }
+
+            let playable: Vec<usize> =
+                (0..WIDTH).filter(|&colm| bit_position.can_play(colm)).collect();
+            if playable.is_empty() {
+                break;
+            }
+            let colm = playable[rng.below(playable.len())];
+            let is_winning = array_position.is_winning(colm);
+            array_position.play(colm);
+            bit_position.play(colm);
+            advance_bit_position.play(colm);
+            if is_winning {
+                break;
+            }
+        }
+
+        // undoing the whole game goes back to the empty board
+        let moves = array_position.history().to_vec();
+        for &colm in moves.iter().rev() {
+            bit_position.reverse_play(colm);
+            advance_bit_position.reverse_play(colm);
+            array_position.undo();
+            check_agreement(array_position.position(), &bit_position, &advance_bit_position);
+        }
+        assert_eq!(bit_position.key(), 0);
+    }
+}
+
+#[test]
+fn conversions_are_lossless() {
+    let mut rng = Rng::new(42);
+    for _ in 0..GAMES {
+        let mut bit_position = BitPosition::new();
+        for _ in 0..rng.below(WIDTH * HEIGHT) {
+            let colm = rng.below(WIDTH);
+            if !bit_position.can_play(colm) || bit_position.is_winning(colm) {
+                break;
+            }
+            bit_position.play(colm);
+        }
+
+        let advance_bit_position = AdvanceBitPosition::from(bit_position);
+        let array_position = ArrayPosition::from(advance_bit_position);
+        check_agreement(&array_position, &bit_position, &advance_bit_position);
+        assert_eq!(BitPosition::from(array_position).key(), bit_position.key());
+        assert_eq!(BitPosition::from(advance_bit_position).key(), bit_position.key());
+    }
+}
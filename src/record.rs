@@ -0,0 +1,269 @@
+use std::fmt;
+
+use crate::positions::{
+    GameOutcome, HEIGHT, WIDTH, array_position::ArrayPosition, try_load_starting_position,
+};
+
+/*
+    a game with its metadata, written as
+
+    [Player1 "alice"]
+    [Player2 "bob"]
+    [Date "2026-10-19"]
+    [Board "7x6"]
+    [TimeControl "300+5"]
+    [Result "1-0"]
+
+    4 4 (1) {the center first} 5 5 3
+
+    the headers are free, Board and Result are checked against the moves when present.
+    A move is a column 1-7, it can be followed by its score in parentheses and a comment in
+    braces, so the plain move strings are valid records without headers
+*/
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct GameRecord {
+    pub headers: Vec<(String, String)>,
+    pub moves: Vec<RecordedMove>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RecordedMove {
+    pub colm: usize,
+    pub score: Option<i32>,
+    pub comment: Option<String>,
+}
+
+pub const BOARD_HEADER: &str = "Board";
+pub const RESULT_HEADER: &str = "Result";
+
+// the Result header of a game, * while it goes on
+pub fn result_name(outcome: GameOutcome) -> &'static str {
+    match outcome {
+        GameOutcome::Win(1) => "1-0",
+        GameOutcome::Win(_) => "0-1",
+        GameOutcome::Draw => "1/2-1/2",
+        GameOutcome::Ongoing => "*",
+    }
+}
+
+fn board_name() -> String {
+    format!("{}x{}", WIDTH, HEIGHT)
+}
+
+impl GameRecord {
+    // a record of the moves with the Board and Result headers
+    pub fn from_moves(moves: &str) -> Result<Self, String> {
+        let outcome = try_load_starting_position(moves, &mut ArrayPosition::new())?;
+        Ok(Self {
+            headers: vec![
+                (BOARD_HEADER.to_string(), board_name()),
+                (RESULT_HEADER.to_string(), result_name(outcome).to_string()),
+            ],
+            moves: moves
+                .bytes()
+                .map(|ch| RecordedMove {
+                    colm: (ch - b'1') as usize,
+                    score: None,
+                    comment: None,
+                })
+                .collect(),
+        })
+    }
+
+    // the plain move string, as read by load_starting_position
+    pub fn to_moves(&self) -> String {
+        self.moves
+            .iter()
+            .map(|recorded| char::from(b'1' + recorded.colm as u8))
+            .collect()
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // replaces the value of the header, new headers go last but before the Result
+    pub fn set_header(&mut self, name: &str, value: &str) {
+        if let Some((_, old_value)) = self.headers.iter_mut().find(|(header, _)| header == name) {
+            *old_value = value.to_string();
+            return;
+        }
+        let ind = match self.headers.last() {
+            Some((header, _)) if header == RESULT_HEADER => self.headers.len() - 1,
+            _ => self.headers.len(),
+        };
+        self.headers
+            .insert(ind, (name.to_string(), value.to_string()));
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut record = Self::default();
+        // the headers come first, the moves start at the first other line that isn't empty
+        let mut moves_start = text.len();
+        let mut offset = 0;
+        for (ind, line) in text.split_inclusive('\n').enumerate() {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('[') {
+                moves_start = offset;
+                break;
+            }
+            if !trimmed.is_empty() {
+                let header =
+                    parse_header(trimmed).map_err(|err| format!("line {}: {}", ind + 1, err))?;
+                record.headers.push(header);
+            }
+            offset += line.len();
+        }
+        parse_moves(&text[moves_start..], &mut record.moves).map_err(|(offset, err)| {
+            let line = 1 + text[..moves_start + offset].matches('\n').count();
+            format!("line {}: {}", line, err)
+        })?;
+
+        let outcome = try_load_starting_position(&record.to_moves(), &mut ArrayPosition::new())?;
+        if let Some(board) = record.header(BOARD_HEADER)
+            && board != board_name()
+        {
+            return Err(format!(
+                "only {} boards are supported, not {}",
+                board_name(),
+                board
+            ));
+        }
+        // a game can also end by resignation or on time, only a finished game is checked
+        if let Some(result) = record.header(RESULT_HEADER)
+            && outcome != GameOutcome::Ongoing
+            && result != result_name(outcome)
+        {
+            return Err(format!(
+                "the result is {} but the moves end in {}",
+                result,
+                result_name(outcome)
+            ));
+        }
+        Ok(record)
+    }
+}
+
+// [Name "value"], the value can escape \ and " with a backslash
+fn parse_header(line: &str) -> Result<(String, String), String> {
+    let inner = line
+        .strip_prefix('[')
+        .and_then(|line| line.strip_suffix(']'))
+        .ok_or("a header is [Name \"value\"]")?;
+    let (name, quoted) = inner
+        .split_once(char::is_whitespace)
+        .ok_or("a header is [Name \"value\"]")?;
+    let mut chars = quoted.trim().chars();
+    if chars.next() != Some('"') {
+        return Err("a header is [Name \"value\"]".to_string());
+    }
+    let value = read_until(&mut chars, '"')?;
+    if chars.next().is_some() {
+        return Err("a header is [Name \"value\"]".to_string());
+    }
+    Ok((name.to_string(), value))
+}
+
+// the text up to the unescaped end character, which is consumed
+fn read_until(chars: &mut std::str::Chars, end: char) -> Result<String, String> {
+    let mut text = String::new();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => text.push(chars.next().ok_or(format!("missing {}", end))?),
+            ch if ch == end => return Ok(text),
+            ch => text.push(ch),
+        }
+    }
+    Err(format!("missing {}", end))
+}
+
+// the moves can span several lines and so can the comments, errors come with their offset
+fn parse_moves(text: &str, moves: &mut Vec<RecordedMove>) -> Result<(), (usize, String)> {
+    let mut chars = text.chars();
+    loop {
+        let offset = text.len() - chars.as_str().len();
+        let Some(ch) = chars.next() else {
+            return Ok(());
+        };
+        let result = match ch {
+            '(' => read_until(&mut chars, ')').and_then(|text| {
+                let score = text
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a score", text))?;
+                moves.last_mut().ok_or("a score must follow a move")?.score = Some(score);
+                Ok(())
+            }),
+            '{' => read_until(&mut chars, '}').and_then(|comment| {
+                moves
+                    .last_mut()
+                    .ok_or("a comment must follow a move")?
+                    .comment = Some(comment);
+                Ok(())
+            }),
+            ch if ch.is_whitespace() => Ok(()),
+            ch => match ch.to_digit(10) {
+                Some(digit @ 1..) if digit as usize <= WIDTH => {
+                    moves.push(RecordedMove {
+                        colm: digit as usize - 1,
+                        score: None,
+                        comment: None,
+                    });
+                    Ok(())
+                }
+                _ => Err(format!("'{}' is not a column", ch)),
+            },
+        };
+        result.map_err(|err| (offset, err))?;
+    }
+}
+
+fn escape(text: &str, end: char) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch == '\\' || ch == end {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, value) in &self.headers {
+            writeln!(f, "[{} \"{}\"]", name, escape(value, '"'))?;
+        }
+        if !self.headers.is_empty() {
+            writeln!(f)?;
+        }
+        // the moves are wrapped before 80 characters
+        let mut line_len = 0;
+        for recorded in &self.moves {
+            let mut token = (recorded.colm + 1).to_string();
+            if let Some(score) = recorded.score {
+                token += &format!(" ({})", score);
+            }
+            if let Some(comment) = &recorded.comment {
+                token += &format!(" {{{}}}", escape(comment, '}'));
+            }
+            if line_len > 0 && line_len + token.len() >= 80 {
+                writeln!(f)?;
+                line_len = 0;
+            }
+            if line_len > 0 {
+                write!(f, " ")?;
+                line_len += 1;
+            }
+            write!(f, "{}", token)?;
+            line_len += token.len();
+        }
+        if line_len > 0 {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
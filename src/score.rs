@@ -0,0 +1,107 @@
+use std::fmt;
+
+use crate::{
+    positions::{HEIGHT, WIDTH},
+    solvers::SolveMode,
+};
+
+/*
+    a positive score means that the player to move wins, the earlier the higher:
+    score = WIDTH * HEIGHT / 2 + 1 - number of stones of the winner when the game is won
+    a negative score means the same for the opponent and 0 is a draw
+*/
+
+// the outcome for the player to move
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Outcome {
+    // the sign is enough, so weak scores can be interpreted too
+    pub fn of(score: i32) -> Self {
+        match score {
+            1.. => Outcome::Win,
+            0 => Outcome::Draw,
+            _ => Outcome::Loss,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Outcome::Win => "win",
+            Outcome::Draw => "draw",
+            Outcome::Loss => "loss",
+        };
+        f.pad(name)
+    }
+}
+
+// what a score means for the game under perfect play
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Interpretation {
+    pub outcome: Outcome,
+    // 1 or 2, the same numbering as GameOutcome::Win
+    pub winner: Option<usize>,
+    // number of stones of the winner including the winning one, only known from exact scores
+    pub winner_stones: Option<usize>,
+    pub plies_to_end: Option<usize>,
+}
+
+impl Interpretation {
+    // score from the point of view of the player to move after played_moves moves,
+    // the mode it was solved with tells whether it is exact
+    pub fn new(score: i32, played_moves: usize, mode: SolveMode) -> Self {
+        let outcome = Outcome::of(score);
+        let player = 1 + (played_moves & 1);
+        let winner = match outcome {
+            Outcome::Win => Some(player),
+            Outcome::Draw => None,
+            Outcome::Loss => Some(3 - player),
+        };
+
+        if mode != SolveMode::Strong {
+            return Self {
+                outcome,
+                winner,
+                winner_stones: None,
+                plies_to_end: None,
+            };
+        }
+
+        let winner_stones = winner.map(|_| (WIDTH * HEIGHT / 2 + 1) - score.unsigned_abs() as usize);
+        let end = match (winner, winner_stones) {
+            // the first player places the n-th stone with the (2n - 1)-th move
+            (Some(1), Some(stones)) => 2 * stones - 1,
+            (Some(_), Some(stones)) => 2 * stones,
+            _ => WIDTH * HEIGHT,
+        };
+
+        Self {
+            outcome,
+            winner,
+            winner_stones,
+            plies_to_end: Some(end - played_moves),
+        }
+    }
+}
+
+impl fmt::Display for Interpretation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.winner {
+            Some(winner) => write!(f, "player {} wins", winner)?,
+            None => write!(f, "draw")?,
+        }
+        if let Some(stones) = self.winner_stones {
+            write!(f, " with stone {}", stones)?;
+        }
+        if let Some(plies) = self.plies_to_end {
+            write!(f, " in {} {}", plies, if plies == 1 { "ply" } else { "plies" })?;
+        }
+        Ok(())
+    }
+}
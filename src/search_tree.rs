@@ -0,0 +1,255 @@
+use std::fmt::Write;
+
+use crate::score::Interpretation;
+
+/*
+    the tree explored by a solver down to max_depth moves below the position it solves,
+    filled by the negamax of the solvers through enter and leave around every search of a node
+    every search of the root by the root driver is a separate root node
+    a tree built with Default records nothing, the solvers keep it when no tree is asked for
+*/
+#[derive(Clone, Default)]
+pub struct SearchTree {
+    enabled: bool,
+    root_moves: String,
+    max_depth: usize,
+    nodes: Vec<TreeNode>,
+    // the recorded nodes from the root to the node being searched
+    path: Vec<usize>,
+    // number of nodes entered and not left yet, recorded or not
+    depth: usize,
+    entered_nodes: usize,
+    // the score of the solve and its meaning, written at the top of the exports when set
+    result: Option<(i32, Interpretation)>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TreeNode {
+    pub parent: Option<usize>,
+    // the column played from the parent, None for the root
+    pub colm: Option<usize>,
+    // the moves from the empty board
+    pub moves: String,
+    pub alpha: i32,
+    pub beta: i32,
+    // the value returned by the search of the node, None while it is searched
+    pub value: Option<i32>,
+    // the upper bound read from the transposition table
+    pub table_bound: Option<i32>,
+    // the column of the move which made the node return with a beta cutoff
+    pub cutoff: Option<usize>,
+    // the nodes searched below this one, including the ones deeper than max_depth
+    pub subtree_nodes: usize,
+    entered_at: usize,
+}
+
+impl SearchTree {
+    // root_moves are the moves of the solved position, they label the nodes
+    pub fn new(root_moves: &str, max_depth: usize) -> Self {
+        Self {
+            enabled: true,
+            root_moves: root_moves.to_string(),
+            max_depth,
+            ..Self::default()
+        }
+    }
+
+    pub fn nodes(&self) -> &[TreeNode] {
+        &self.nodes
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.path.clear();
+        self.depth = 0;
+        self.entered_nodes = 0;
+        self.result = None;
+    }
+
+    pub fn with_result(mut self, score: i32, interpretation: Interpretation) -> Self {
+        self.result = Some((score, interpretation));
+        self
+    }
+
+    // the node being searched, when it is recorded
+    fn current(&mut self) -> Option<&mut TreeNode> {
+        if self.depth == 0 || self.depth > self.max_depth + 1 {
+            return None;
+        }
+        let ind = *self.path.last()?;
+        Some(&mut self.nodes[ind])
+    }
+
+    #[inline]
+    pub fn enter(&mut self, colm: Option<usize>, alpha: i32, beta: i32) {
+        if !self.enabled {
+            return;
+        }
+        self.entered_nodes += 1;
+        self.depth += 1;
+        if self.depth > self.max_depth + 1 {
+            return;
+        }
+        let parent = if self.depth == 1 {
+            None
+        } else {
+            self.path.last().copied()
+        };
+        let mut moves = match parent {
+            Some(parent) => self.nodes[parent].moves.clone(),
+            None => self.root_moves.clone(),
+        };
+        if let Some(colm) = colm {
+            moves.push(char::from(b'1' + colm as u8));
+        }
+        self.path.push(self.nodes.len());
+        self.nodes.push(TreeNode {
+            parent,
+            colm,
+            moves,
+            alpha,
+            beta,
+            value: None,
+            table_bound: None,
+            cutoff: None,
+            subtree_nodes: 0,
+            entered_at: self.entered_nodes,
+        });
+    }
+
+    #[inline]
+    pub fn leave(&mut self, value: i32) {
+        if !self.enabled {
+            return;
+        }
+        let entered_nodes = self.entered_nodes;
+        if let Some(node) = self.current() {
+            node.value = Some(value);
+            node.subtree_nodes = entered_nodes - node.entered_at;
+            self.path.pop();
+        }
+        self.depth -= 1;
+    }
+
+    #[inline]
+    pub fn table_hit(&mut self, upper_bound: i32) {
+        if self.enabled
+            && let Some(node) = self.current()
+        {
+            node.table_bound = Some(upper_bound);
+        }
+    }
+
+    #[inline]
+    pub fn cutoff(&mut self, colm: usize) {
+        if self.enabled
+            && let Some(node) = self.current()
+        {
+            node.cutoff = Some(colm);
+        }
+    }
+
+    /*
+        Graphviz DOT, a box per node with its moves, window and value, the cutoffs in red,
+        the table hits in blue and the nodes hiding deeper searches with their size
+    */
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph search {\n    node [shape=box, fontname=\"monospace\"];\n");
+        if let Some((score, interpretation)) = &self.result {
+            writeln!(
+                dot,
+                "    label=\"score {}, {}\";\n    labelloc=t;",
+                score, interpretation
+            )
+            .unwrap();
+        }
+        let mut has_children = vec![false; self.nodes.len()];
+        for node in &self.nodes {
+            if let Some(parent) = node.parent {
+                has_children[parent] = true;
+            }
+        }
+        for (ind, node) in self.nodes.iter().enumerate() {
+            let mut label = format!(
+                "{}\\n[{}, {}] -> {}",
+                if node.moves.is_empty() { "-" } else { &node.moves },
+                node.alpha,
+                node.beta,
+                node.value.map_or("?".to_string(), |value| value.to_string())
+            );
+            if let Some(bound) = node.table_bound {
+                write!(label, "\\ntable <= {}", bound).unwrap();
+            }
+            if let Some(colm) = node.cutoff {
+                write!(label, "\\ncutoff by {}", colm + 1).unwrap();
+            }
+            if !has_children[ind] && node.subtree_nodes > 0 {
+                write!(label, "\\n+{} nodes", node.subtree_nodes).unwrap();
+            }
+            let color = match (node.cutoff, node.table_bound) {
+                (Some(_), _) => ", style=filled, fillcolor=\"#f4b6b6\"",
+                (None, Some(_)) => ", style=filled, fillcolor=\"#b6d4f4\"",
+                (None, None) => "",
+            };
+            writeln!(dot, "    n{} [label=\"{}\"{}];", ind, label, color).unwrap();
+            if let (Some(parent), Some(colm)) = (node.parent, node.colm) {
+                writeln!(dot, "    n{} -> n{} [label=\"{}\"];", parent, ind, colm + 1).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    // a flat list of the nodes, the parents are indices in the list
+    pub fn to_json(&self) -> String {
+        fn optional(value: Option<impl ToString>) -> String {
+            value.map_or("null".to_string(), |value| value.to_string())
+        }
+
+        let mut json = format!(
+            "{{\n  \"root\": \"{}\",\n  \"max_depth\": {},\n",
+            self.root_moves, self.max_depth
+        );
+        if let Some((score, interpretation)) = &self.result {
+            write!(
+                json,
+                "  \"score\": {},\n  \"interpretation\": {{\"outcome\": \"{}\", \"winner\": {}, \
+                 \"winner_stones\": {}, \"plies_to_end\": {}, \"text\": \"{}\"}},\n",
+                score,
+                interpretation.outcome,
+                optional(interpretation.winner),
+                optional(interpretation.winner_stones),
+                optional(interpretation.plies_to_end),
+                interpretation
+            )
+            .unwrap();
+        }
+        json.push_str("  \"nodes\": [");
+        for (ind, node) in self.nodes.iter().enumerate() {
+            write!(
+                json,
+                "{}\n    {{\"id\": {}, \"parent\": {}, \"column\": {}, \"moves\": \"{}\", \
+                 \"alpha\": {}, \"beta\": {}, \"value\": {}, \"table_bound\": {}, \
+                 \"cutoff\": {}, \"subtree_nodes\": {}}}",
+                if ind == 0 { "" } else { "," },
+                ind,
+                optional(node.parent),
+                optional(node.colm.map(|colm| colm + 1)),
+                node.moves,
+                node.alpha,
+                node.beta,
+                optional(node.value),
+                optional(node.table_bound),
+                optional(node.cutoff.map(|colm| colm + 1)),
+                node.subtree_nodes
+            )
+            .unwrap();
+        }
+        json.push_str(if self.nodes.is_empty() { "]\n}\n" } else { "\n  ]\n}\n" });
+        json
+    }
+}
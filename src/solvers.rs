@@ -1,14 +1,68 @@
-use crate::positions::{HEIGHT, WIDTH};
+use crate::{
+    positions::{GameOutcome, HEIGHT, WIDTH},
+    search_tree::SearchTree,
+};
 
 // it needs at least 4 moves to win
 pub const MIN_SCORE: i32 = -(WIDTH as i32 * HEIGHT as i32) / 2 + 3;
 pub const MAX_SCORE: i32 = (WIDTH as i32 * HEIGHT as i32 + 1) / 2 - 3;
 
+// the question asked to a solver
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SolveMode {
+    // the exact score
+    Strong,
+    // only the sign of the score, who wins
+    Weak,
+    // the exact score if it is strictly inside the window, otherwise a bound on the same side
+    Window(i32, i32),
+    // whether the score is at least the threshold, see Solver::is_at_least
+    AtLeast(i32),
+}
+
+impl SolveMode {
+    // the alpha beta window searched from a position with the given number of played moves
+    pub fn window(self, played_moves: usize) -> (i32, i32) {
+        match self {
+            SolveMode::Strong => (
+                -((WIDTH * HEIGHT - played_moves) as i32) / 2,
+                (WIDTH * HEIGHT - played_moves + 1) as i32 / 2,
+            ),
+            SolveMode::Weak => (-1, 1),
+            SolveMode::Window(alpha, beta) => (alpha, beta),
+            SolveMode::AtLeast(threshold) => (threshold - 1, threshold),
+        }
+    }
+}
+
 pub trait Solver {
-    fn solve(&mut self) -> i32;
+    fn solve(&mut self, mode: SolveMode) -> i32;
     fn explored_nodes(&self) -> usize;
+    // the next solves are about another position, the transposition table is kept,
+    // its entries are bounds on the scores whatever position the search started from
+    fn set_position(&mut self, encoded_position: &str);
+
+    fn is_at_least(&mut self, threshold: i32) -> bool {
+        self.solve(SolveMode::AtLeast(threshold)) >= threshold
+    }
+
+    // the tree explored by the last solve, for the solvers built with a search tree
+    fn search_tree(&self) -> Option<&SearchTree> {
+        None
+    }
+}
+
+// score of a finished game from the point of view of the player to move
+pub fn finished_game_score(outcome: GameOutcome, played_moves: usize) -> Option<i32> {
+    match outcome {
+        GameOutcome::Ongoing => None,
+        // the opponent has won with the last played move
+        GameOutcome::Win(_) => Some(-(((WIDTH * HEIGHT - played_moves + 2) / 2) as i32)),
+        GameOutcome::Draw => Some(0),
+    }
 }
 
+pub mod pvs_solver;
 pub mod move_score_solver;
 pub mod avoid_losing_moves_solver;
 pub mod iterative_deepening_solver;
@@ -17,3 +71,6 @@ pub mod bitboard_solver;
 pub mod center_columns_solver;
 pub mod alpha_beta_solver;
 pub mod negamax_solver;
+pub mod progress;
+pub mod registry;
+pub mod root_driver;
@@ -1,20 +1,20 @@
-use std::{cmp::max, i32};
+use std::cmp::max;
 
 use crate::{
-    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition},
-    solvers::Solver,
+    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition, from_moves},
+    search_tree::SearchTree,
+    solvers::{SolveMode, Solver},
 };
 
 pub struct BitBoardSolver {
     position: BitPosition,
-    alpha: i32,
-    beta: i32,
     explored_nodes: usize,
+    tree: SearchTree,
     column_order: [usize; WIDTH],
 }
 
 impl BitBoardSolver {
-    pub fn new(position: BitPosition, alpha: i32, beta: i32) -> Self {
+    pub fn new(position: BitPosition) -> Self {
         let mut column_order = [0; WIDTH];
 
         // [3, 2, 4, 1, 5, 0, 6]
@@ -26,12 +26,17 @@ impl BitBoardSolver {
         Self {
             position,
             explored_nodes: 0,
-            alpha,
-            beta,
+            tree: SearchTree::default(),
             column_order,
         }
     }
 
+    // records the explored tree, see Solver::search_tree
+    pub fn with_search_tree(mut self, tree: SearchTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
     fn negamax(&mut self, mut alpha: i32, mut beta: i32) -> i32 {
         self.explored_nodes += 1;
         if self.position.played_moves() == WIDTH * HEIGHT {
@@ -57,25 +62,42 @@ impl BitBoardSolver {
             if self.position.can_play(colm) {
                 let old_position = self.position;
                 self.position.play(colm);
-                alpha = max(alpha, -self.negamax(-beta, -alpha));
+                self.tree.enter(Some(colm), -beta, -alpha);
+                let value = self.negamax(-beta, -alpha);
+                self.tree.leave(value);
+                alpha = max(alpha, -value);
                 self.position = old_position;
                 if alpha >= beta {
+                    self.tree.cutoff(colm);
                     return alpha;
                 }
             }
         }
-        return alpha;
+        alpha
     }
 }
 
 impl Solver for BitBoardSolver {
-    fn solve(&mut self) -> i32 {
+    fn solve(&mut self, mode: SolveMode) -> i32 {
         self.explored_nodes = 0;
-        self.negamax(self.alpha, self.beta)
+        self.tree.clear();
+        let (alpha, beta) = mode.window(self.position.played_moves());
+        self.tree.enter(None, alpha, beta);
+        let value = self.negamax(alpha, beta);
+        self.tree.leave(value);
+        value
     }
 
     fn explored_nodes(&self) -> usize {
         self.explored_nodes
     }
+
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
+    fn search_tree(&self) -> Option<&SearchTree> {
+        Some(&self.tree)
+    }
 }
 
@@ -1,21 +1,30 @@
-use std::{cmp::max, i32};
+use std::{cmp::max, sync::Arc};
 
 use crate::{
-    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition},
-    solvers::{MIN_SCORE, Solver}, transposition_table::TranspositionTable,
+    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition, from_moves},
+    search_tree::SearchTree,
+    solvers::{
+        MIN_SCORE, SolveMode, Solver,
+        progress::{Observer, Progress, ScoreRange},
+        root_driver::RootDriver,
+    },
+    transposition_table::TranspositionTable,
 };
 
 pub struct IterativeDeepeningSolver {
     position: BitPosition,
-    alpha: i32,
-    beta: i32,
     table: TranspositionTable,
     explored_nodes: usize,
+    tree: SearchTree,
     column_order: [usize; WIDTH],
+    driver: RootDriver,
+    observer: Option<Arc<dyn Observer>>,
+    // explored nodes at which the observer hears about the progress next
+    next_report: usize,
 }
 
 impl IterativeDeepeningSolver {
-    pub fn new(position: BitPosition, alpha: i32, beta: i32, table: TranspositionTable) -> Self {
+    pub fn new(position: BitPosition, table: TranspositionTable) -> Self {
         let mut column_order = [0; WIDTH];
 
         // [3, 2, 4, 1, 5, 0, 6]
@@ -27,15 +36,47 @@ impl IterativeDeepeningSolver {
         Self {
             position,
             explored_nodes: 0,
-            alpha,
-            beta,
+            tree: SearchTree::default(),
             table,
             column_order,
+            driver: RootDriver::default(),
+            observer: None,
+            next_report: usize::MAX,
+        }
+    }
+
+    // records the explored tree, see Solver::search_tree
+    pub fn with_search_tree(mut self, tree: SearchTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    pub fn with_driver(mut self, driver: RootDriver) -> Self {
+        self.driver = driver;
+        self
+    }
+
+    pub fn with_observer(mut self, observer: Option<Arc<dyn Observer>>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    fn progress(&self) -> Progress {
+        Progress::new(self.explored_nodes, &self.table)
+    }
+
+    fn report_nodes(&mut self) {
+        if let Some(observer) = &self.observer {
+            observer.nodes(&self.progress());
+            self.next_report += observer.node_interval().max(1);
         }
     }
 
     fn negamax(&mut self, mut alpha: i32, mut beta: i32) -> i32 {
         self.explored_nodes += 1;
+        if self.explored_nodes == self.next_report {
+            self.report_nodes();
+        }
         if self.position.played_moves() == WIDTH * HEIGHT {
             return 0;
         }
@@ -48,7 +89,8 @@ impl IterativeDeepeningSolver {
 
         let mut upper_bound = (WIDTH * HEIGHT - self.position.played_moves() - 1) as i32 / 2;
         if let Some(value) = self.table.get(self.position.key()) {
-            upper_bound = value as i32 + MIN_SCORE  as i32 - 1;
+            upper_bound = value as i32 + MIN_SCORE - 1;
+            self.tree.table_hit(upper_bound);
         }
         if upper_bound < beta {
             beta = upper_bound;
@@ -62,49 +104,54 @@ impl IterativeDeepeningSolver {
             if self.position.can_play(colm) {
                 let old_position = self.position;
                 self.position.play(colm);
-                alpha = max(alpha, -self.negamax(-beta, -alpha));
+                self.tree.enter(Some(colm), -beta, -alpha);
+                let value = self.negamax(-beta, -alpha);
+                self.tree.leave(value);
+                alpha = max(alpha, -value);
                 self.position = old_position;
                 if alpha >= beta {
+                    self.tree.cutoff(colm);
                     return alpha;
                 }
             }
         }
         self.table.put(self.position.key(), (alpha - MIN_SCORE + 1) as u8);
-        return alpha;
+        alpha
     }
 }
 
 impl Solver for IterativeDeepeningSolver {
-    fn solve(&mut self) -> i32 {
+    fn solve(&mut self, mode: SolveMode) -> i32 {
         self.explored_nodes = 0;
+        self.tree.clear();
+        self.next_report = self
+            .observer
+            .as_ref()
+            .map_or(usize::MAX, |observer| observer.node_interval().max(1));
         
-        if self.alpha != -1 && self.beta != 1 {
-            self.alpha =  -((WIDTH * HEIGHT - self.position.played_moves()) as i32) / 2;
-            self.beta =  (WIDTH * HEIGHT - self.position.played_moves() + 1) as i32 / 2;
-        }
-
-        let mut left = self.alpha;
-        let mut right = self.beta;
+        let (alpha, beta) = mode.window(self.position.played_moves());
 
-        while left < right {
-            let mut median = left + (right - left) / 2;
-            if median <= 0  &&  left/2 < median {
-                median = left / 2;
-            } else if median >= 0 && median < right / 2 {
-                median = right / 2;
+        let mut range = ScoreRange::new(alpha, beta);
+        self.driver.search(alpha, beta, |alpha, beta| {
+            self.tree.enter(None, alpha, beta);
+            let value = self.negamax(alpha, beta);
+            self.tree.leave(value);
+            if let Some(observer) = &self.observer {
+                observer.iteration(&range.narrow(alpha, beta, value), &self.progress());
             }
-            let score = self.negamax(median, median + 1);
-            if score <= median {
-                right = score;
-            } else {
-                left = score;
-            }
-        }
-
-        left
+            value
+        })
     }
 
     fn explored_nodes(&self) -> usize {
         self.explored_nodes
     }
+
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
+    fn search_tree(&self) -> Option<&SearchTree> {
+        Some(&self.tree)
+    }
 }
\ No newline at end of file
@@ -1,31 +1,98 @@
-use std::{cmp::max, i32};
+use std::{cmp::max, sync::Arc};
 
 use crate::{
-    move_sorter::MoveSorter,
+    move_sorter::{Heuristics, MoveOrdering, MoveSorter},
     positions::{
         HEIGHT, WIDTH,
-        advance_bit_position::{AdvanceBitPosition, column_mask},
+        advance_bit_position::{AdvanceBitPosition, column_mask, move_column},
+        from_moves,
     },
-    solvers::{MIN_SCORE, Solver},
+    search_tree::SearchTree,
+    solvers::{
+        MIN_SCORE, SolveMode, Solver,
+        progress::{Observer, Progress, ScoreRange},
+        root_driver::RootDriver,
+    },
+    tablebase::Tablebase,
     transposition_table::TranspositionTable,
 };
 
+// what a node of the advance bitboard solvers knows before its moves are searched
+pub(crate) enum Prologue {
+    Value(i32),
+    // the non losing moves and the window narrowed by the bounds of the score
+    Search { next: u64, alpha: i32, beta: i32 },
+}
+
+/*
+    probes the tablebase, looks for the forced losses and the draws, and narrows the window
+    with the lower bound of a position the opponent can't win next and the upper bound stored
+    in the transposition table
+*/
+pub(crate) fn prologue(
+    position: &AdvanceBitPosition,
+    table: &TranspositionTable,
+    tablebase: Option<&Tablebase>,
+    tree: &mut SearchTree,
+    mut alpha: i32,
+    mut beta: i32,
+) -> Prologue {
+    if let Some(tablebase) = tablebase
+        && let Some(score) = tablebase.probe(position)
+    {
+        return Prologue::Value(score);
+    }
+
+    let next = position.possible_non_losing_moves();
+    if next == 0 {
+        return Prologue::Value(-(((WIDTH * HEIGHT - position.played_moves()) / 2) as i32));
+    }
+
+    if position.played_moves() >= WIDTH * HEIGHT - 2 {
+        // draw
+        return Prologue::Value(0);
+    }
+
+    // opponent cannot win with his next move
+    let lower_bound = -(((WIDTH * HEIGHT - position.played_moves() - 2) / 2) as i32);
+    if alpha < lower_bound {
+        alpha = lower_bound;
+        if alpha >= beta {
+            return Prologue::Value(alpha);
+        }
+    }
+
+    let mut upper_bound = (WIDTH * HEIGHT - position.played_moves() - 1) as i32 / 2;
+    if let Some(value) = table.get(position.key()) {
+        upper_bound = value as i32 + MIN_SCORE - 1;
+        tree.table_hit(upper_bound);
+    }
+    if upper_bound < beta {
+        beta = upper_bound;
+        if alpha >= beta {
+            return Prologue::Value(beta);
+        }
+    }
+
+    Prologue::Search { next, alpha, beta }
+}
+
 pub struct MoveScoreSolver {
     position: AdvanceBitPosition,
-    alpha: i32,
-    beta: i32,
     table: TranspositionTable,
     explored_nodes: usize,
+    tree: SearchTree,
     column_order: [usize; WIDTH],
+    driver: RootDriver,
+    observer: Option<Arc<dyn Observer>>,
+    // explored nodes at which the observer hears about the progress next
+    next_report: usize,
+    ordering: MoveOrdering,
+    tablebase: Option<Arc<Tablebase>>,
 }
 
 impl MoveScoreSolver {
-    pub fn new(
-        position: AdvanceBitPosition,
-        alpha: i32,
-        beta: i32,
-        table: TranspositionTable,
-    ) -> Self {
+    pub fn new(position: AdvanceBitPosition, table: TranspositionTable) -> Self {
         let mut column_order = [0; WIDTH];
 
         // [3, 2, 4, 1, 5, 0, 6]
@@ -37,105 +104,165 @@ impl MoveScoreSolver {
         Self {
             position,
             explored_nodes: 0,
-            alpha,
-            beta,
+            tree: SearchTree::default(),
             table,
             column_order,
+            driver: RootDriver::default(),
+            observer: None,
+            next_report: usize::MAX,
+            ordering: MoveOrdering::new(Heuristics::default()),
+            tablebase: None,
         }
     }
 
-    fn negamax(&mut self, mut alpha: i32, mut beta: i32) -> i32 {
-        self.explored_nodes += 1;
+    // records the explored tree, see Solver::search_tree
+    pub fn with_search_tree(mut self, tree: SearchTree) -> Self {
+        self.tree = tree;
+        self
+    }
 
-        let next = self.position.possible_non_losing_moves();
-        if next == 0 {
-            return -(((WIDTH * HEIGHT - self.position.played_moves()) / 2) as i32);
-        }
+    pub fn with_driver(mut self, driver: RootDriver) -> Self {
+        self.driver = driver;
+        self
+    }
 
-        if self.position.played_moves() >= WIDTH * HEIGHT - 2 {
-            // draw
-            return 0;
-        }
+    pub fn with_observer(mut self, observer: Option<Arc<dyn Observer>>) -> Self {
+        self.observer = observer;
+        self
+    }
 
-        // opponent cannot win with his next move
-        let lower_bound = -(((WIDTH * HEIGHT - self.position.played_moves() - 2) / 2) as i32);
-        if alpha < lower_bound {
-            alpha = lower_bound;
-            if alpha >= beta {
-                return alpha;
-            }
-        }
+    fn progress(&self) -> Progress {
+        Progress::new(self.explored_nodes, &self.table)
+    }
 
-        let mut upper_bound = (WIDTH * HEIGHT - self.position.played_moves() - 1) as i32 / 2;
-        if let Some(value) = self.table.get(self.position.key()) {
-            upper_bound = value as i32 + MIN_SCORE as i32 - 1;
+    fn report_nodes(&mut self) {
+        if let Some(observer) = &self.observer {
+            observer.nodes(&self.progress());
+            self.next_report += observer.node_interval().max(1);
         }
-        if upper_bound < beta {
-            beta = upper_bound;
-            if alpha >= beta {
-                return beta;
-            }
+    }
+
+    pub fn with_heuristics(mut self, heuristics: Heuristics) -> Self {
+        self.ordering = MoveOrdering::new(heuristics);
+        self
+    }
+
+    // the endgame positions stored in the tablebase aren't searched
+    pub fn with_tablebase(mut self, tablebase: Option<Arc<Tablebase>>) -> Self {
+        self.tablebase = tablebase;
+        self
+    }
+
+    fn negamax(&mut self, alpha: i32, beta: i32) -> i32 {
+        self.explored_nodes += 1;
+        if self.explored_nodes == self.next_report {
+            self.report_nodes();
         }
 
+        let (next, mut alpha, beta) = match prologue(
+            &self.position,
+            &self.table,
+            self.tablebase.as_deref(),
+            &mut self.tree,
+            alpha,
+            beta,
+        ) {
+            Prologue::Value(value) => return value,
+            Prologue::Search { next, alpha, beta } => (next, alpha, beta),
+        };
+
+        let played_moves = self.position.played_moves();
+        let hash_move = if self.ordering.uses_hash_move() {
+            self.table.get_move(self.position.key())
+        } else {
+            None
+        };
         let mut moves = MoveSorter::new();
         for ind in (0..WIDTH).rev() {
             let colm = self.column_order[ind];
             let mov = next & column_mask(colm);
             if mov != 0 {
-                moves.add(mov, self.position.score(mov));
+                let score = if hash_move == Some(colm) {
+                    // the move stored from the previous search goes first
+                    i32::MAX
+                } else {
+                    let threats = self.position.score(mov);
+                    self.ordering.score(threats, mov, played_moves)
+                };
+                moves.add(mov, score);
             }
         }
 
+        // only a move that raises alpha has an exact score, when all of them fail low their
+        // scores are upper bounds and none of them is known to be the best
+        let mut best_move = None;
         for mov in moves {
             let old_position = self.position;
             self.position.play_move(mov);
-            alpha = max(alpha, -self.negamax(-beta, -alpha));
+            self.tree.enter(Some(move_column(mov)), -beta, -alpha);
+            let value = self.negamax(-beta, -alpha);
+            self.tree.leave(value);
+            let score = -value;
             self.position = old_position;
+            if score > alpha {
+                best_move = Some(mov);
+            }
+            alpha = max(alpha, score);
             if alpha >= beta {
+                self.tree.cutoff(move_column(mov));
+                self.ordering.cutoff(mov, played_moves);
+                if self.ordering.uses_hash_move() {
+                    self.table.put_move(self.position.key(), move_column(mov));
+                }
                 return alpha;
             }
         }
         self.table
             .put(self.position.key(), (alpha - MIN_SCORE + 1) as u8);
-        return alpha;
+        if let Some(mov) = best_move.filter(|_| self.ordering.uses_hash_move()) {
+            self.table.put_move(self.position.key(), move_column(mov));
+        }
+        alpha
     }
 }
 
 impl Solver for MoveScoreSolver {
-    fn solve(&mut self) -> i32 {
+    fn solve(&mut self, mode: SolveMode) -> i32 {
         self.explored_nodes = 0;
+        self.tree.clear();
+        self.next_report = self
+            .observer
+            .as_ref()
+            .map_or(usize::MAX, |observer| observer.node_interval().max(1));
+        self.ordering.clear();
 
         if self.position.can_win_next() {
             return (WIDTH * HEIGHT - self.position.played_moves() + 1) as i32 / 2;
         }
 
-        if self.alpha != -1 && self.beta != 1 {
-            self.alpha =  -((WIDTH * HEIGHT - self.position.played_moves()) as i32) / 2;
-            self.beta =  (WIDTH * HEIGHT - self.position.played_moves() + 1) as i32 / 2;
-        }
-
-        let mut left = self.alpha;
-        let mut right = self.beta;
+        let (alpha, beta) = mode.window(self.position.played_moves());
 
-        while left < right {
-            let mut median = left + (right - left) / 2;
-            if median <= 0 && left / 2 < median {
-                median = left / 2;
-            } else if median >= 0 && median < right / 2 {
-                median = right / 2;
-            }
-            let score = self.negamax(median, median + 1);
-            if score <= median {
-                right = score;
-            } else {
-                left = score;
+        let mut range = ScoreRange::new(alpha, beta);
+        self.driver.search(alpha, beta, |alpha, beta| {
+            self.tree.enter(None, alpha, beta);
+            let value = self.negamax(alpha, beta);
+            self.tree.leave(value);
+            if let Some(observer) = &self.observer {
+                observer.iteration(&range.narrow(alpha, beta, value), &self.progress());
             }
-        }
-
-        left
+            value
+        })
     }
 
     fn explored_nodes(&self) -> usize {
         self.explored_nodes
     }
+
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
+    fn search_tree(&self) -> Option<&SearchTree> {
+        Some(&self.tree)
+    }
 }
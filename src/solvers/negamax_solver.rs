@@ -1,6 +1,6 @@
-use std::{cmp::max, i32};
+use std::cmp::max;
 
-use crate::{positions::{HEIGHT, Position, WIDTH, array_position::ArrayPosition}, solvers::Solver};
+use crate::{positions::{HEIGHT, Position, WIDTH, array_position::ArrayPosition, from_moves}, solvers::{SolveMode, Solver}};
 
 pub struct NegamaxSolver {
     position: ArrayPosition,
@@ -33,12 +33,13 @@ impl NegamaxSolver {
             }
         }
 
-        return best_score;
+        best_score
     }
 }
 
 impl Solver for NegamaxSolver {
-    fn solve(&mut self) -> i32 {
+    // the exact score answers every mode
+    fn solve(&mut self, _mode: SolveMode) -> i32 {
         self.explored_nodes = 0;
         self.negamax()
     }
@@ -47,4 +48,8 @@ impl Solver for NegamaxSolver {
         self.explored_nodes
     }
 
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
 }
\ No newline at end of file
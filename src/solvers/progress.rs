@@ -0,0 +1,89 @@
+use crate::transposition_table::TranspositionTable;
+
+pub const DEFAULT_NODE_INTERVAL: usize = 1 << 20;
+
+// where a solve stands
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Progress {
+    pub explored_nodes: usize,
+    pub table_filled: usize,
+    pub table_size: usize,
+}
+
+impl Progress {
+    pub fn new(explored_nodes: usize, table: &TranspositionTable) -> Self {
+        Self {
+            explored_nodes,
+            table_filled: table.filled(),
+            table_size: table.size(),
+        }
+    }
+
+    // share of the transposition table in use, between 0 and 1
+    pub fn table_fill(&self) -> f64 {
+        self.table_filled as f64 / self.table_size.max(1) as f64
+    }
+}
+
+/*
+    one search of the root by the root driver with the window [alpha, beta], alpha is the
+    median of the null windows, and the range [left, right] the score is known to be in after it
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Iteration {
+    pub alpha: i32,
+    pub beta: i32,
+    pub score: i32,
+    pub left: i32,
+    pub right: i32,
+}
+
+// the range of the score narrowed by the searches of the root, whatever the driver
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScoreRange {
+    left: i32,
+    right: i32,
+}
+
+impl ScoreRange {
+    pub fn new(alpha: i32, beta: i32) -> Self {
+        Self {
+            left: alpha,
+            right: beta,
+        }
+    }
+
+    // a score outside the window is a bound on the same side, inside it is exact
+    pub fn narrow(&mut self, alpha: i32, beta: i32, score: i32) -> Iteration {
+        if score <= alpha {
+            self.right = self.right.min(score);
+        } else if score >= beta {
+            self.left = self.left.max(score);
+        } else {
+            self.left = score;
+            self.right = score;
+        }
+        Iteration {
+            alpha,
+            beta,
+            score,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+/*
+    notified by the solvers with a root driver during a solve, shared by the solvers built with
+    the same options, so the state of a progress bar has to live behind a lock or atomics
+*/
+pub trait Observer: Send + Sync {
+    fn iteration(&self, _iteration: &Iteration, _progress: &Progress) {}
+
+    // every node_interval explored nodes
+    fn nodes(&self, _progress: &Progress) {}
+
+    fn node_interval(&self) -> usize {
+        DEFAULT_NODE_INTERVAL
+    }
+}
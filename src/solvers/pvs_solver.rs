@@ -0,0 +1,188 @@
+use std::{cmp::max, sync::Arc};
+
+use crate::{
+    move_sorter::MoveSorter,
+    positions::{
+        HEIGHT, WIDTH,
+        advance_bit_position::{AdvanceBitPosition, column_mask, move_column},
+        from_moves,
+    },
+    search_tree::SearchTree,
+    solvers::{
+        MIN_SCORE, SolveMode, Solver,
+        move_score_solver::{Prologue, prologue},
+        progress::{Observer, Progress, ScoreRange},
+    },
+    tablebase::Tablebase,
+    transposition_table::TranspositionTable,
+};
+
+/*
+    principal variation search (negascout)
+    the first move is searched with the full window, the others only have to prove
+    that they aren't better with a null window and are searched again if they are
+*/
+pub struct PvsSolver {
+    position: AdvanceBitPosition,
+    table: TranspositionTable,
+    explored_nodes: usize,
+    tree: SearchTree,
+    column_order: [usize; WIDTH],
+    observer: Option<Arc<dyn Observer>>,
+    // explored nodes at which the observer hears about the progress next
+    next_report: usize,
+    tablebase: Option<Arc<Tablebase>>,
+}
+
+impl PvsSolver {
+    pub fn new(position: AdvanceBitPosition, table: TranspositionTable) -> Self {
+        let mut column_order = [0; WIDTH];
+
+        // [3, 2, 4, 1, 5, 0, 6]
+        for ind in 0..(WIDTH as i32) {
+            let colm = WIDTH as i32 / 2 + (1 - 2 * (ind & 1)) * (ind + 1) / 2;
+            column_order[ind as usize] = colm as usize;
+        }
+
+        Self {
+            position,
+            explored_nodes: 0,
+            tree: SearchTree::default(),
+            table,
+            column_order,
+            observer: None,
+            next_report: usize::MAX,
+            tablebase: None,
+        }
+    }
+
+    // records the explored tree, see Solver::search_tree
+    pub fn with_search_tree(mut self, tree: SearchTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    // hears about the single search of the root and the explored nodes
+    pub fn with_observer(mut self, observer: Option<Arc<dyn Observer>>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    // the endgame positions stored in the tablebase aren't searched
+    pub fn with_tablebase(mut self, tablebase: Option<Arc<Tablebase>>) -> Self {
+        self.tablebase = tablebase;
+        self
+    }
+
+    fn progress(&self) -> Progress {
+        Progress::new(self.explored_nodes, &self.table)
+    }
+
+    fn report_nodes(&mut self) {
+        if let Some(observer) = &self.observer {
+            observer.nodes(&self.progress());
+            self.next_report += observer.node_interval().max(1);
+        }
+    }
+
+    // a child searched with the window [alpha, beta] of the parent, the value is the parent's
+    fn search_child(&mut self, mov: u64, alpha: i32, beta: i32) -> i32 {
+        self.tree.enter(Some(move_column(mov)), -beta, -alpha);
+        let value = self.negamax(-beta, -alpha);
+        self.tree.leave(value);
+        -value
+    }
+
+    fn negamax(&mut self, alpha: i32, beta: i32) -> i32 {
+        self.explored_nodes += 1;
+        if self.explored_nodes == self.next_report {
+            self.report_nodes();
+        }
+
+        let (next, mut alpha, beta) = match prologue(
+            &self.position,
+            &self.table,
+            self.tablebase.as_deref(),
+            &mut self.tree,
+            alpha,
+            beta,
+        ) {
+            Prologue::Value(value) => return value,
+            Prologue::Search { next, alpha, beta } => (next, alpha, beta),
+        };
+
+        let mut moves = MoveSorter::new();
+        for ind in (0..WIDTH).rev() {
+            let colm = self.column_order[ind];
+            let mov = next & column_mask(colm);
+            if mov != 0 {
+                moves.add(mov, self.position.score(mov));
+            }
+        }
+
+        let mut first = true;
+        for mov in moves {
+            let old_position = self.position;
+            self.position.play_move(mov);
+            let score = if first {
+                self.search_child(mov, alpha, beta)
+            } else {
+                let score = self.search_child(mov, alpha, alpha + 1);
+                if alpha < score && score < beta {
+                    // better than the principal variation, the exact score is needed
+                    self.search_child(mov, alpha, beta)
+                } else {
+                    score
+                }
+            };
+            self.position = old_position;
+            first = false;
+
+            alpha = max(alpha, score);
+            if alpha >= beta {
+                self.tree.cutoff(move_column(mov));
+                return alpha;
+            }
+        }
+        self.table
+            .put(self.position.key(), (alpha - MIN_SCORE + 1) as u8);
+        alpha
+    }
+}
+
+impl Solver for PvsSolver {
+    fn solve(&mut self, mode: SolveMode) -> i32 {
+        self.explored_nodes = 0;
+        self.tree.clear();
+        self.next_report = self
+            .observer
+            .as_ref()
+            .map_or(usize::MAX, |observer| observer.node_interval().max(1));
+
+        if self.position.can_win_next() {
+            return (WIDTH * HEIGHT - self.position.played_moves() + 1) as i32 / 2;
+        }
+
+        let (alpha, beta) = mode.window(self.position.played_moves());
+        self.tree.enter(None, alpha, beta);
+        let value = self.negamax(alpha, beta);
+        self.tree.leave(value);
+        if let Some(observer) = &self.observer {
+            let iteration = ScoreRange::new(alpha, beta).narrow(alpha, beta, value);
+            observer.iteration(&iteration, &self.progress());
+        }
+        value
+    }
+
+    fn explored_nodes(&self) -> usize {
+        self.explored_nodes
+    }
+
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
+    fn search_tree(&self) -> Option<&SearchTree> {
+        Some(&self.tree)
+    }
+}
@@ -0,0 +1,425 @@
+use std::{fmt, sync::Arc};
+
+use crate::{
+    move_sorter::Heuristics,
+    positions::{
+        advance_bit_position::AdvanceBitPosition, array_position::ArrayPosition,
+        bit_position::BitPosition, from_moves,
+    },
+    search_tree::SearchTree,
+    solvers::{
+        SolveMode, Solver, alpha_beta_solver::AlphaBetaSolver,
+        avoid_losing_moves_solver::AvoidLosingMovesSolver, bitboard_solver::BitBoardSolver,
+        center_columns_solver::CenterColumnsSolver,
+        iterative_deepening_solver::IterativeDeepeningSolver, move_score_solver::MoveScoreSolver,
+        negamax_solver::NegamaxSolver, progress::Observer, pvs_solver::PvsSolver,
+        root_driver::RootDriver,
+        transposition_table_solver::TranspositionTableSolver,
+    },
+    tablebase::Tablebase,
+    transposition_table::TranspositionTable,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PositionKind {
+    Array,
+    Bit,
+    AdvanceBit,
+}
+
+impl fmt::Display for PositionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PositionKind::Array => "ArrayPosition",
+            PositionKind::Bit => "BitPosition",
+            PositionKind::AdvanceBit => "AdvanceBitPosition",
+        };
+        f.pad(name)
+    }
+}
+
+// weak solvers only find out who wins, strong ones the exact score
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Strength {
+    Weak,
+    Strong,
+}
+
+impl Strength {
+    fn prefix(self) -> &'static str {
+        match self {
+            Strength::Weak => "weak",
+            Strength::Strong => "strong",
+        }
+    }
+
+    pub fn mode(self) -> SolveMode {
+        match self {
+            Strength::Weak => SolveMode::Weak,
+            Strength::Strong => SolveMode::Strong,
+        }
+    }
+}
+
+// what the solvers are built with besides the position, solvers ignore what they don't use
+#[derive(Clone)]
+pub struct BuildOptions {
+    pub table_size: usize,
+    pub tablebase: Option<Arc<Tablebase>>,
+    // records the explored tree down to this depth, see Solver::search_tree
+    pub search_tree_depth: Option<usize>,
+    // notified of the progress by the solvers with a root driver and the pvs solver
+    pub observer: Option<Arc<dyn Observer>>,
+    // first guess of the score for the mtdf and aspiration drivers
+    pub guess: i32,
+}
+
+impl BuildOptions {
+    pub fn new(table_size: usize) -> Self {
+        Self {
+            table_size,
+            tablebase: None,
+            search_tree_depth: None,
+            observer: None,
+            guess: 0,
+        }
+    }
+}
+
+// builds the solver from the encoded position
+type Build = fn(&str, &BuildOptions) -> Box<dyn Solver>;
+
+pub struct SolverEntry {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub position: PositionKind,
+    pub strengths: &'static [Strength],
+    build: Build,
+}
+
+impl SolverEntry {
+    // solvers with a single strength are selected by their bare name, the others by "weak-name"
+    // and "strong-name"
+    pub fn variant_name(&self, strength: Strength) -> String {
+        if self.strengths.len() == 1 {
+            self.name.to_string()
+        } else {
+            format!("{}-{}", strength.prefix(), self.name)
+        }
+    }
+
+    pub fn build(&self, encoded_position: &str, options: &BuildOptions) -> Box<dyn Solver> {
+        (self.build)(encoded_position, options)
+    }
+}
+
+fn search_tree(encoded_position: &str, options: &BuildOptions) -> SearchTree {
+    match options.search_tree_depth {
+        Some(max_depth) => SearchTree::new(encoded_position, max_depth),
+        None => SearchTree::default(),
+    }
+}
+
+fn mtdf(options: &BuildOptions) -> RootDriver {
+    RootDriver::Mtdf {
+        guess: options.guess,
+    }
+}
+
+fn aspiration(options: &BuildOptions) -> RootDriver {
+    RootDriver::Aspiration {
+        guess: options.guess,
+        delta: 2,
+    }
+}
+
+fn iterative_deepening(encoded_position: &str, options: &BuildOptions) -> IterativeDeepeningSolver {
+    IterativeDeepeningSolver::new(
+        from_moves::<BitPosition>(encoded_position),
+        TranspositionTable::new(options.table_size),
+    )
+    .with_search_tree(search_tree(encoded_position, options))
+    .with_observer(options.observer.clone())
+}
+
+fn avoid_losing_moves(encoded_position: &str, options: &BuildOptions) -> AvoidLosingMovesSolver {
+    AvoidLosingMovesSolver::new(
+        from_moves::<AdvanceBitPosition>(encoded_position),
+        TranspositionTable::new(options.table_size),
+    )
+    .with_search_tree(search_tree(encoded_position, options))
+    .with_observer(options.observer.clone())
+}
+
+fn move_score(encoded_position: &str, options: &BuildOptions) -> MoveScoreSolver {
+    MoveScoreSolver::new(
+        from_moves::<AdvanceBitPosition>(encoded_position),
+        TranspositionTable::new(options.table_size),
+    )
+    .with_tablebase(options.tablebase.clone())
+    .with_search_tree(search_tree(encoded_position, options))
+    .with_observer(options.observer.clone())
+}
+
+const BOTH: &[Strength] = &[Strength::Weak, Strength::Strong];
+
+pub const SOLVERS: &[SolverEntry] = &[
+    SolverEntry {
+        name: "negamax",
+        description: "negamax with array board",
+        position: PositionKind::Array,
+        strengths: &[Strength::Strong],
+        build: |encoded_position, _| {
+            Box::new(NegamaxSolver::new(from_moves::<ArrayPosition>(encoded_position)))
+        },
+    },
+    SolverEntry {
+        name: "alpha-beta",
+        description: "alpha beta pruning negamax with array board",
+        position: PositionKind::Array,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                AlphaBetaSolver::new(from_moves::<ArrayPosition>(encoded_position))
+                    .with_search_tree(search_tree(encoded_position, options)),
+            )
+        },
+    },
+    SolverEntry {
+        name: "center-columns",
+        description: "alpha beta pruning negamax with array board and center columns heuristic",
+        position: PositionKind::Array,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                CenterColumnsSolver::new(from_moves::<ArrayPosition>(encoded_position))
+                    .with_search_tree(search_tree(encoded_position, options)),
+            )
+        },
+    },
+    SolverEntry {
+        name: "bitboard",
+        description: "alpha beta pruning negamax with bitboard and center columns heuristic",
+        position: PositionKind::Bit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                BitBoardSolver::new(from_moves::<BitPosition>(encoded_position))
+                    .with_search_tree(search_tree(encoded_position, options)),
+            )
+        },
+    },
+    SolverEntry {
+        name: "transposition-table",
+        description: "alpha beta pruning negamax with bitboard, \
+                      center columns heuristic and transposition table",
+        position: PositionKind::Bit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                TranspositionTableSolver::new(
+                    from_moves::<BitPosition>(encoded_position),
+                    TranspositionTable::new(options.table_size),
+                )
+                .with_search_tree(search_tree(encoded_position, options)),
+            )
+        },
+    },
+    SolverEntry {
+        name: "iterative-deepening",
+        description: "iterative deepening negamax with bitboard, \
+                      center columns heuristic and transposition table",
+        position: PositionKind::Bit,
+        strengths: BOTH,
+        build: |encoded_position, options| Box::new(iterative_deepening(encoded_position, options)),
+    },
+    SolverEntry {
+        name: "iterative-deepening-mtdf",
+        description: "iterative deepening solver driven by MTD(f) null windows starting from --guess",
+        position: PositionKind::Bit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(iterative_deepening(encoded_position, options).with_driver(mtdf(options)))
+        },
+    },
+    SolverEntry {
+        name: "iterative-deepening-aspiration",
+        description: "iterative deepening solver driven by aspiration windows around --guess",
+        position: PositionKind::Bit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                iterative_deepening(encoded_position, options).with_driver(aspiration(options)),
+            )
+        },
+    },
+    SolverEntry {
+        name: "avoid-losing-moves",
+        description: "iterative deepening negamax with advance bitboard, \
+                      center columns heuristic and transposition table",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| Box::new(avoid_losing_moves(encoded_position, options)),
+    },
+    SolverEntry {
+        name: "avoid-losing-moves-mtdf",
+        description: "avoid losing moves solver driven by MTD(f) null windows starting from --guess",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(avoid_losing_moves(encoded_position, options).with_driver(mtdf(options)))
+        },
+    },
+    SolverEntry {
+        name: "avoid-losing-moves-aspiration",
+        description: "avoid losing moves solver driven by aspiration windows around --guess",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(avoid_losing_moves(encoded_position, options).with_driver(aspiration(options)))
+        },
+    },
+    SolverEntry {
+        name: "move-score",
+        description: "iterative deepening negamax with advance bitboard, \
+                      move score heuristic and transposition table",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| Box::new(move_score(encoded_position, options)),
+    },
+    SolverEntry {
+        name: "killer-moves",
+        description: "move score solver with killer moves breaking the ties",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                move_score(encoded_position, options).with_heuristics(Heuristics {
+                    killer_moves: true,
+                    history: false,
+                    hash_move: false,
+                }),
+            )
+        },
+    },
+    SolverEntry {
+        name: "history-heuristic",
+        description: "move score solver with the history heuristic breaking the ties",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                move_score(encoded_position, options).with_heuristics(Heuristics {
+                    killer_moves: false,
+                    history: true,
+                    hash_move: false,
+                }),
+            )
+        },
+    },
+    SolverEntry {
+        name: "killer-history",
+        description: "move score solver with killer moves and the history heuristic breaking the ties",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                move_score(encoded_position, options).with_heuristics(Heuristics {
+                    killer_moves: true,
+                    history: true,
+                    hash_move: false,
+                }),
+            )
+        },
+    },
+    SolverEntry {
+        name: "hash-move",
+        description: "move score solver trying the best move stored in the transposition table first",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                move_score(encoded_position, options).with_heuristics(Heuristics {
+                    killer_moves: false,
+                    history: false,
+                    hash_move: true,
+                }),
+            )
+        },
+    },
+    SolverEntry {
+        name: "mtdf",
+        description: "move score solver driven by MTD(f) null windows starting from --guess",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(move_score(encoded_position, options).with_driver(mtdf(options)))
+        },
+    },
+    SolverEntry {
+        name: "aspiration",
+        description: "move score solver driven by aspiration windows around --guess",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(move_score(encoded_position, options).with_driver(aspiration(options)))
+        },
+    },
+    SolverEntry {
+        name: "pvs",
+        description: "principal variation search with advance bitboard, \
+                      move score heuristic and transposition table",
+        position: PositionKind::AdvanceBit,
+        strengths: BOTH,
+        build: |encoded_position, options| {
+            Box::new(
+                PvsSolver::new(
+                    from_moves::<AdvanceBitPosition>(encoded_position),
+                    TranspositionTable::new(options.table_size),
+                )
+                .with_tablebase(options.tablebase.clone())
+                .with_search_tree(search_tree(encoded_position, options))
+                .with_observer(options.observer.clone()),
+            )
+        },
+    },
+];
+
+// every selectable solver name together with its entry, in registration order
+pub fn variants() -> impl Iterator<Item = (String, &'static SolverEntry, Strength)> {
+    SOLVERS.iter().flat_map(|entry| {
+        entry
+            .strengths
+            .iter()
+            .map(move |&strength| (entry.variant_name(strength), entry, strength))
+    })
+}
+
+pub fn find_solver(solver_name: &str) -> Option<(&'static SolverEntry, Strength)> {
+    variants()
+        .find(|(name, _, _)| name == solver_name)
+        .map(|(_, entry, strength)| (entry, strength))
+}
+
+// builds the solver registered under solver_name together with the mode its name asks for,
+// None if there is no such solver
+pub fn build_solver(
+    solver_name: &str,
+    encoded_position: &str,
+    table_size: usize,
+) -> Option<(Box<dyn Solver>, SolveMode)> {
+    build_solver_with(
+        solver_name,
+        encoded_position,
+        &BuildOptions::new(table_size),
+    )
+}
+
+pub fn build_solver_with(
+    solver_name: &str,
+    encoded_position: &str,
+    options: &BuildOptions,
+) -> Option<(Box<dyn Solver>, SolveMode)> {
+    let (entry, strength) = find_solver(solver_name)?;
+    Some((entry.build(encoded_position, options), strength.mode()))
+}
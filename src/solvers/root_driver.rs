@@ -0,0 +1,85 @@
+use std::cmp::{max, min};
+
+/*
+    strategies for finding the exact score inside [alpha, beta] with repeated searches,
+    search(alpha, beta) returns the score when it is strictly inside the window,
+    otherwise a bound on the same side of the window
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RootDriver {
+    // null windows bisecting the score range, biased towards 0 where most scores are
+    #[default]
+    Bisection,
+    // null windows around the last bound, starting from a guess
+    Mtdf { guess: i32 },
+    // a window of +-delta around the guess, widened on every fail
+    Aspiration { guess: i32, delta: i32 },
+}
+
+impl RootDriver {
+    pub fn search(self, alpha: i32, beta: i32, mut search: impl FnMut(i32, i32) -> i32) -> i32 {
+        match self {
+            RootDriver::Bisection => {
+                let mut left = alpha;
+                let mut right = beta;
+
+                while left < right {
+                    let mut median = left + (right - left) / 2;
+                    if median <= 0 && left / 2 < median {
+                        median = left / 2;
+                    } else if median >= 0 && median < right / 2 {
+                        median = right / 2;
+                    }
+                    let score = search(median, median + 1);
+                    if score <= median {
+                        right = score;
+                    } else {
+                        left = score;
+                    }
+                }
+
+                left
+            }
+            RootDriver::Mtdf { guess } => {
+                let mut lower = alpha;
+                let mut upper = beta;
+                let mut score = guess.clamp(alpha, beta);
+
+                while lower < upper {
+                    let bound = max(score, lower + 1);
+                    score = search(bound - 1, bound);
+                    if score < bound {
+                        upper = score;
+                    } else {
+                        lower = score;
+                    }
+                }
+
+                lower
+            }
+            RootDriver::Aspiration { guess, delta } => {
+                let guess = guess.clamp(alpha, beta);
+                let mut delta = max(delta, 1);
+                let mut low = max(alpha, guess - delta);
+                let mut high = min(beta, guess + delta);
+
+                loop {
+                    let score = search(low, high);
+                    if score <= low && low > alpha {
+                        // the score is at most the returned bound
+                        delta *= 2;
+                        high = score + 1;
+                        low = max(alpha, score - delta);
+                    } else if score >= high && high < beta {
+                        // the score is at least the returned bound
+                        delta *= 2;
+                        low = score - 1;
+                        high = min(beta, score + delta);
+                    } else {
+                        return score;
+                    }
+                }
+            }
+        }
+    }
+}
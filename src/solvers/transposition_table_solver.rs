@@ -1,21 +1,21 @@
-use std::{cmp::max, i32};
+use std::cmp::max;
 
 use crate::{
-    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition},
-    solvers::{MIN_SCORE, Solver}, transposition_table::TranspositionTable,
+    positions::{HEIGHT, Position, WIDTH, bit_position::BitPosition, from_moves},
+    search_tree::SearchTree,
+    solvers::{MIN_SCORE, SolveMode, Solver}, transposition_table::TranspositionTable,
 };
 
 pub struct TranspositionTableSolver {
     position: BitPosition,
-    alpha: i32,
-    beta: i32,
     table: TranspositionTable,
     explored_nodes: usize,
+    tree: SearchTree,
     column_order: [usize; WIDTH],
 }
 
 impl TranspositionTableSolver {
-    pub fn new(position: BitPosition, alpha: i32, beta: i32, table: TranspositionTable) -> Self {
+    pub fn new(position: BitPosition, table: TranspositionTable) -> Self {
         let mut column_order = [0; WIDTH];
 
         // [3, 2, 4, 1, 5, 0, 6]
@@ -27,13 +27,18 @@ impl TranspositionTableSolver {
         Self {
             position,
             explored_nodes: 0,
-            alpha,
-            beta,
+            tree: SearchTree::default(),
             table,
             column_order,
         }
     }
 
+    // records the explored tree, see Solver::search_tree
+    pub fn with_search_tree(mut self, tree: SearchTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
     fn negamax(&mut self, mut alpha: i32, mut beta: i32) -> i32 {
         self.explored_nodes += 1;
         if self.position.played_moves() == WIDTH * HEIGHT {
@@ -48,7 +53,8 @@ impl TranspositionTableSolver {
 
         let mut upper_bound = (WIDTH * HEIGHT - self.position.played_moves() - 1) as i32 / 2;
         if let Some(value) = self.table.get(self.position.key()) {
-            upper_bound = value as i32 + MIN_SCORE  as i32 - 1;
+            upper_bound = value as i32 + MIN_SCORE - 1;
+            self.tree.table_hit(upper_bound);
         }
         if upper_bound < beta {
             beta = upper_bound;
@@ -62,26 +68,43 @@ impl TranspositionTableSolver {
             if self.position.can_play(colm) {
                 let old_position = self.position;
                 self.position.play(colm);
-                alpha = max(alpha, -self.negamax(-beta, -alpha));
+                self.tree.enter(Some(colm), -beta, -alpha);
+                let value = self.negamax(-beta, -alpha);
+                self.tree.leave(value);
+                alpha = max(alpha, -value);
                 self.position = old_position;
                 if alpha >= beta {
+                    self.tree.cutoff(colm);
                     return alpha;
                 }
             }
         }
         self.table.put(self.position.key(), (alpha - MIN_SCORE + 1) as u8);
-        return alpha;
+        alpha
     }
 }
 
 impl Solver for TranspositionTableSolver {
-    fn solve(&mut self) -> i32 {
+    fn solve(&mut self, mode: SolveMode) -> i32 {
         self.explored_nodes = 0;
-        self.negamax(self.alpha, self.beta)
+        self.tree.clear();
+        let (alpha, beta) = mode.window(self.position.played_moves());
+        self.tree.enter(None, alpha, beta);
+        let value = self.negamax(alpha, beta);
+        self.tree.leave(value);
+        value
     }
 
     fn explored_nodes(&self) -> usize {
         self.explored_nodes
     }
+
+    fn set_position(&mut self, encoded_position: &str) {
+        self.position = from_moves(encoded_position);
+    }
+
+    fn search_tree(&self) -> Option<&SearchTree> {
+        Some(&self.tree)
+    }
 }
 
@@ -0,0 +1,315 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use crate::positions::{
+    HEIGHT, Position, WIDTH,
+    advance_bit_position::{AdvanceBitPosition, column_mask},
+};
+
+const MAGIC: &[u8; 4] = b"C4TB";
+
+/*
+    exact scores of every ongoing position with at least min_stones stones reachable from a root,
+    the entries are key << 8 | (score - MIN_SCORE) sorted by key, the key is unique for a position
+    on disk: magic, min_stones (u8), number of entries (u64) and the entries, all little endian
+*/
+pub struct Tablebase {
+    min_stones: usize,
+    entries: Vec<u64>,
+}
+
+// the score fits in the low byte, it is at least -(WIDTH * HEIGHT / 2)
+const SCORE_OFFSET: i32 = (WIDTH * HEIGHT / 2) as i32;
+
+impl Tablebase {
+    /*
+        the positions with min_stones stones are generated directly by filling the columns above
+        the root, the shallower plies are never visited. The following plies are played forwards
+        from them, then the scores are computed backwards starting from the full board, each ply
+        only needs the scores of the next one
+    */
+    pub fn build(root: AdvanceBitPosition, min_stones: usize) -> Self {
+        let first_ply = min_stones.max(root.played_moves());
+        let mut plies = vec![HashMap::new(); WIDTH * HEIGHT + 1];
+        plies[first_ply] = fill_columns(root, first_ply);
+        for played_moves in first_ply..WIDTH * HEIGHT {
+            let (ply, next_plies) = plies[played_moves..].split_first_mut().unwrap();
+            for position in ply.values() {
+                for colm in 0..WIDTH {
+                    // a winning move ends the game
+                    if position.can_play(colm) && !position.is_winning(colm) {
+                        let mut child = *position;
+                        child.play(colm);
+                        next_plies[0].insert(child.key(), child);
+                    }
+                }
+            }
+        }
+
+        let mut next_scores: HashMap<u64, i32> = HashMap::new();
+        let mut entries = Vec::new();
+        for played_moves in (first_ply..=WIDTH * HEIGHT).rev() {
+            let mut scores = HashMap::new();
+            for (&key, position) in &plies[played_moves] {
+                let score = backward_score(position, &next_scores);
+                scores.insert(key, score);
+                entries.push(key << 8 | (score + SCORE_OFFSET) as u64);
+            }
+            next_scores = scores;
+        }
+        entries.sort_unstable();
+
+        Self {
+            min_stones,
+            entries,
+        }
+    }
+
+    pub fn min_stones(&self) -> usize {
+        self.min_stones
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, key: u64) -> Option<i32> {
+        let ind = self
+            .entries
+            .binary_search_by_key(&key, |entry| entry >> 8)
+            .ok()?;
+        Some((self.entries[ind] & 0xFF) as i32 - SCORE_OFFSET)
+    }
+
+    // the exact score of the position if it is stored
+    pub fn probe(&self, position: &AdvanceBitPosition) -> Option<i32> {
+        if position.played_moves() < self.min_stones {
+            return None;
+        }
+        self.get(position.key())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[self.min_stones as u8])?;
+        writer.write_all(&(self.entries.len() as u64).to_le_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(&entry.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a tablebase file",
+            ));
+        }
+
+        let mut min_stones = [0; 1];
+        reader.read_exact(&mut min_stones)?;
+        let mut buffer = [0; 8];
+        reader.read_exact(&mut buffer)?;
+        let count = u64::from_le_bytes(buffer);
+        // checked before allocating, a corrupt count could ask for any amount of memory
+        let header_len = (MAGIC.len() + 1 + 8) as u64;
+        if count
+            .checked_mul(8)
+            .and_then(|len| len.checked_add(header_len))
+            != Some(file_len)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the number of entries doesn't match the file size",
+            ));
+        }
+        let count = count as usize;
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            reader.read_exact(&mut buffer)?;
+            entries.push(u64::from_le_bytes(buffer));
+        }
+        if !entries.is_sorted() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unsorted tablebase entries",
+            ));
+        }
+
+        Ok(Self {
+            min_stones: min_stones[0] as usize,
+            entries,
+        })
+    }
+}
+
+// the bits of the cells in the same layout as AdvanceBitPosition
+fn cell_bit(colm: usize, row: usize) -> u64 {
+    1 << (colm * (HEIGHT + 1) + row)
+}
+
+fn has_four(stones: u64) -> bool {
+    // vertical, horizontal and both diagonals
+    [1, HEIGHT + 1, HEIGHT, HEIGHT + 2].iter().any(|&shift| {
+        let pairs = stones & (stones >> shift);
+        pairs & (pairs >> (2 * shift)) != 0
+    })
+}
+
+// the stones of the first and the second player
+#[derive(Clone, Copy)]
+struct Stones {
+    players: [u64; 2],
+}
+
+impl Stones {
+    fn mask(&self) -> u64 {
+        self.players[0] | self.players[1]
+    }
+}
+
+/*
+    the ongoing positions with stones stones reachable from the root, the columns are filled
+    above the stones of the root from left to right and bottom to top with the right number of
+    stones of each player and without four in a row, then the positions that no order of moves
+    reaches from the root are dropped
+*/
+fn fill_columns(root: AdvanceBitPosition, stones: usize) -> HashMap<u64, AdvanceBitPosition> {
+    // the player to move owns the current stones
+    let to_move = root.played_moves() & 1;
+    let mut players = [0; 2];
+    players[to_move] = root.current();
+    players[1 - to_move] = root.current() ^ root.mask();
+    let root_stones = Stones { players };
+    let missing = [
+        stones.div_ceil(2) - players[0].count_ones() as usize,
+        stones / 2 - players[1].count_ones() as usize,
+    ];
+
+    let mut positions = HashMap::new();
+    let mut filler = Filler {
+        root,
+        positions: &mut positions,
+    };
+    filler.fill(root_stones, 0, missing);
+    positions
+}
+
+struct Filler<'a> {
+    root: AdvanceBitPosition,
+    positions: &'a mut HashMap<u64, AdvanceBitPosition>,
+}
+
+impl Filler<'_> {
+    // fills the column from its first empty cell, the next columns are filled recursively
+    fn fill(&mut self, stones: Stones, colm: usize, missing: [usize; 2]) {
+        if missing == [0, 0] {
+            if let Some(position) = self.play_to(stones) {
+                self.positions.insert(position.key(), position);
+            }
+            return;
+        }
+        if colm == WIDTH {
+            return;
+        }
+        // the cells left in this column and the next ones
+        let free = (colm..WIDTH)
+            .map(|colm| {
+                (0..HEIGHT)
+                    .filter(|&row| stones.mask() & cell_bit(colm, row) == 0)
+                    .count()
+            })
+            .sum::<usize>();
+        if free < missing[0] + missing[1] {
+            return;
+        }
+
+        // the column can stop at any height, the next stone goes on the first empty cell
+        self.fill(stones, colm + 1, missing);
+        let Some(row) = (0..HEIGHT).find(|&row| stones.mask() & cell_bit(colm, row) == 0) else {
+            return;
+        };
+        for player in 0..2 {
+            if missing[player] == 0 {
+                continue;
+            }
+            let mut next = stones;
+            next.players[player] |= cell_bit(colm, row);
+            if has_four(next.players[player]) {
+                continue;
+            }
+            let mut next_missing = missing;
+            next_missing[player] -= 1;
+            self.fill(next, colm, next_missing);
+        }
+    }
+
+    // plays the stones in an order that alternates the players, None if there is none
+    fn play_to(&self, stones: Stones) -> Option<AdvanceBitPosition> {
+        let mut order = Vec::new();
+        let mut dead = HashSet::new();
+        if !find_order(&stones, self.root.mask(), &mut order, &mut dead) {
+            return None;
+        }
+        let mut position = self.root;
+        for colm in order {
+            position.play(colm);
+        }
+        Some(position)
+    }
+}
+
+// depth first search of the move orders, dead holds the masks from which no order is left
+fn find_order(stones: &Stones, mask: u64, order: &mut Vec<usize>, dead: &mut HashSet<u64>) -> bool {
+    if mask == stones.mask() {
+        return true;
+    }
+    if dead.contains(&mask) {
+        return false;
+    }
+    let player = mask.count_ones() as usize & 1;
+    for colm in 0..WIDTH {
+        let next = (mask + cell_bit(colm, 0)) & !mask & column_mask(colm);
+        if next & stones.players[player] != 0 {
+            order.push(colm);
+            if find_order(stones, mask | next, order, dead) {
+                return true;
+            }
+            order.pop();
+        }
+    }
+    dead.insert(mask);
+    false
+}
+
+fn backward_score(position: &AdvanceBitPosition, next_scores: &HashMap<u64, i32>) -> i32 {
+    if position.can_win_next() {
+        return (WIDTH * HEIGHT - position.played_moves() + 1) as i32 / 2;
+    }
+
+    // no move left is a draw
+    let mut best = None;
+    for colm in (0..WIDTH).filter(|&colm| position.can_play(colm)) {
+        let mut child = *position;
+        child.play(colm);
+        let score = -next_scores[&child.key()];
+        best = Some(best.map_or(score, |best: i32| best.max(score)));
+    }
+    best.unwrap_or(0)
+}
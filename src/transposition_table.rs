@@ -1,14 +1,26 @@
 pub struct TranspositionTable {
-    table: Vec<Entry>
+    table: Vec<Entry>,
+    // number of entries in use
+    filled: usize,
 }
 
+/*
+    key << 11 | best move << 8 | value
+    the best move is stored as column + 1 and the value is shifted above 0,
+    so 0 in both means that nothing is stored (even for the empty board, which has key 0)
+*/
 #[derive(Clone, Copy)]
 struct Entry(u64);
 
 impl Entry {
     #[inline]
     fn key(self) -> u64 {
-        self.0 >> 8
+        self.0 >> 11
+    }
+
+    #[inline]
+    fn best_move(self) -> u8 {
+        ((self.0 >> 8) & 0x7) as u8
     }
 
     #[inline]
@@ -17,33 +29,71 @@ impl Entry {
     }
 
     #[inline]
-    fn new(key: u64, value: u8) -> Self {
-        Entry((key << 8) | value as u64)
+    fn new(key: u64, best_move: u8, value: u8) -> Self {
+        Entry((key << 11) | (best_move as u64) << 8 | value as u64)
     }
 }
 
 impl TranspositionTable {
     pub fn new(size: usize) -> Self {
-        Self { table: vec![Entry(0); size] }
+        Self {
+            table: vec![Entry(0); size],
+            filled: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    fn store(&mut self, ind: usize, entry: Entry) {
+        if self.table[ind].0 == 0 {
+            self.filled += 1;
+        }
+        self.table[ind] = entry;
     }
 
     fn index(&self, key: u64) -> usize {
         key as usize % self.table.len()
     }
 
-    pub fn get(&self, key: u64) -> Option<u8> {
-        let ind = self.index(key);
-        let entry = self.table[ind];
+    fn entry(&self, key: u64) -> Option<Entry> {
+        let entry = self.table[self.index(key)];
         if entry.key() == key {
-            Some(entry.value())
+            Some(entry)
         } else {
             None
         }
     }
 
+    pub fn get(&self, key: u64) -> Option<u8> {
+        self.entry(key)
+            .map(|entry| entry.value())
+            .filter(|&value| value != 0)
+    }
+
+    pub fn get_move(&self, key: u64) -> Option<usize> {
+        self.entry(key)
+            .map(|entry| entry.best_move())
+            .filter(|&best_move| best_move != 0)
+            .map(|best_move| best_move as usize - 1)
+    }
+
+    // keeps the best move if the position is already stored
     pub fn put(&mut self, key: u64, value: u8) {
+        let best_move = self.entry(key).map_or(0, |entry| entry.best_move());
         let ind = self.index(key);
-        let entry = Entry::new(key, value);
-        self.table[ind] = entry;
+        self.store(ind, Entry::new(key, best_move, value));
+    }
+
+    // keeps the value if the position is already stored
+    pub fn put_move(&mut self, key: u64, colm: usize) {
+        let value = self.entry(key).map_or(0, |entry| entry.value());
+        let ind = self.index(key);
+        self.store(ind, Entry::new(key, colm as u8 + 1, value));
     }
-}
\ No newline at end of file
+}
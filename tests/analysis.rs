@@ -0,0 +1,58 @@
+use project::{
+    analysis::{ColumnScorer, best_column, column_scores},
+    solvers::SolveMode,
+};
+
+const TABLE_SIZE: usize = 1048573;
+
+#[test]
+fn scores_of_every_column() {
+    // the first player threatens both ends of the bottom row, the second player can only block
+    // one of them and loses with the fourth stone of the first player whatever they play
+    let scores = column_scores("strong-move-score", "44556", TABLE_SIZE).unwrap();
+    assert_eq!(scores, [Some(-18); 7]);
+
+    let scores = column_scores("strong-move-score", "445566", TABLE_SIZE).unwrap();
+    assert_eq!(scores[6], Some(18));
+    assert_eq!(scores[2], Some(18));
+    assert_eq!(best_column(&scores), Some(2));
+}
+
+#[test]
+fn full_columns_and_unknown_solvers() {
+    let scores = column_scores("strong-move-score", "121212", TABLE_SIZE).unwrap();
+    assert_eq!(scores[0], Some(18));
+    assert_eq!(
+        column_scores("unknown", "4", TABLE_SIZE),
+        Err("unknown solver 'unknown'".to_string())
+    );
+    assert!(column_scores("strong-move-score", "48", TABLE_SIZE).is_err());
+    assert!(column_scores("strong-move-score", "4455667", TABLE_SIZE).is_err());
+
+    // the first player wins immediately in the 1st, 3rd or 7th column
+    let scores = column_scores("strong-move-score", "444444121253536213", TABLE_SIZE).unwrap();
+    assert_eq!(scores, [Some(12), Some(-12), Some(12), None, Some(-12), Some(-12), Some(12)]);
+    assert_eq!(best_column(&scores), Some(2));
+}
+
+#[test]
+fn scorer_keeps_its_table_between_positions() {
+    let mut scorer = ColumnScorer::new("weak-move-score", TABLE_SIZE).unwrap();
+    assert_eq!(scorer.mode(), SolveMode::Weak);
+    for position in ["444444121253536213", "4444441212535362", "445566"] {
+        let strong = column_scores("strong-move-score", position, TABLE_SIZE).unwrap();
+        assert_eq!(
+            scorer.scores(position).unwrap(),
+            strong.map(|score| score.map(i32::signum))
+        );
+    }
+    assert!(scorer.scores("4455667").is_err());
+    assert!(ColumnScorer::new("unknown", TABLE_SIZE).is_err());
+}
+
+#[test]
+fn best_column_prefers_the_center() {
+    assert_eq!(best_column(&[Some(1); 7]), Some(3));
+    assert_eq!(best_column(&[Some(1), None, None, None, None, None, Some(1)]), Some(0));
+    assert_eq!(best_column(&[None; 7]), None);
+}
@@ -0,0 +1,62 @@
+use project::annotation::{MoveAnnotation, annotate_game};
+
+const TABLE_SIZE: usize = 1048573;
+
+#[test]
+fn scores_and_blunders() {
+    let annotations =
+        annotate_game("strong-move-score", "4444441212535362133", 14, TABLE_SIZE).unwrap();
+    let played: Vec<(usize, usize, usize)> = annotations
+        .iter()
+        .map(|annotation| (annotation.ply, annotation.player, annotation.colm))
+        .collect();
+    assert_eq!(
+        played,
+        [(14, 1, 5), (15, 2, 1), (16, 1, 0), (17, 2, 2), (18, 1, 2)]
+    );
+
+    // a win kept with a later stone, then a win thrown away by both players
+    let losses: Vec<(i32, bool)> = annotations
+        .iter()
+        .map(|annotation| (annotation.lost(), annotation.is_blunder()))
+        .collect();
+    assert_eq!(
+        losses,
+        [(1, false), (2, false), (26, true), (25, true), (0, false)]
+    );
+    assert_eq!(
+        annotations[2],
+        MoveAnnotation {
+            ply: 16,
+            player: 1,
+            colm: 0,
+            score: -13,
+            best_colm: 6,
+            best_score: 13
+        }
+    );
+
+    // a weak solver only sees the flips
+    let annotations =
+        annotate_game("weak-move-score", "4444441212535362133", 14, TABLE_SIZE).unwrap();
+    let blunders: Vec<bool> = annotations.iter().map(MoveAnnotation::is_blunder).collect();
+    assert_eq!(blunders, [false, false, true, true, false]);
+}
+
+#[test]
+fn invalid_games() {
+    let error = |moves| annotate_game("strong-move-score", moves, 0, TABLE_SIZE).unwrap_err();
+    assert_eq!(error("44556671"), "move 8: the game is already over");
+    assert_eq!(error("1111111"), "move 7: column 1 is full");
+    assert_eq!(error("140"), "move 3: '0' is not a column");
+    assert_eq!(error("1a"), "move 2: 'a' is not a column");
+    assert_eq!(
+        annotate_game("unknown", "4", 0, TABLE_SIZE).unwrap_err(),
+        "unknown solver 'unknown'"
+    );
+    assert!(
+        annotate_game("strong-move-score", "4455", 4, TABLE_SIZE)
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,67 @@
+use project::{
+    batch::solve_batch,
+    solvers::registry::{BuildOptions, Strength, build_solver, variants},
+};
+
+const TABLE_SIZE: usize = 1048573;
+
+fn labelled(content: &'static str) -> Vec<(&'static str, i32)> {
+    content
+        .lines()
+        .map(|line| {
+            let (position, score) = line.split_once(' ').unwrap();
+            (position, score.parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn solutions_keep_the_order_of_the_positions() {
+    let mut samples = labelled(include_str!("data/Test_L3_R1"));
+    samples.extend(
+        labelled(include_str!("data/Test_L2_R1"))
+            .into_iter()
+            .take(10),
+    );
+    // finished games are scored without a search
+    samples.push(("1212121", -18));
+    samples.push(("547125662261271266215743771576315353334444", 0));
+    let positions: Vec<&str> = samples.iter().map(|(position, _)| *position).collect();
+
+    let options = BuildOptions::new(TABLE_SIZE);
+    let single = solve_batch("strong-move-score", &positions, &options, 1).unwrap();
+    let parallel = solve_batch("strong-move-score", &positions, &options, 3).unwrap();
+    assert_eq!(single.len(), samples.len());
+    for ((solution, other), (position, expected)) in single.iter().zip(&parallel).zip(&samples) {
+        assert_eq!(solution.score, *expected, "{}", position);
+        assert_eq!(other.score, *expected, "{}", position);
+    }
+    assert_eq!(single.last().unwrap().explored_nodes, 0);
+
+    assert!(
+        solve_batch("strong-move-score", &[], &options, 4)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(solve_batch("unknown", &positions, &options, 4).is_none());
+}
+
+#[test]
+fn solvers_move_to_the_next_position() {
+    let samples = labelled(include_str!("data/Test_L3_R1"));
+    for (name, _, strength) in variants() {
+        let (mut solver, mode) = build_solver(&name, samples[0].0, TABLE_SIZE).unwrap();
+        for (ind, (position, expected)) in samples.iter().take(5).enumerate() {
+            if ind > 0 {
+                solver.set_position(position);
+            }
+            let score = solver.solve(mode);
+            match strength {
+                Strength::Weak => {
+                    assert_eq!(score.signum(), expected.signum(), "{} {}", name, position)
+                }
+                Strength::Strong => assert_eq!(score, *expected, "{} {}", name, position),
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use project::{
+    dataset::{bucket, bucket_name, parse_line, random_playout},
+    positions::{
+        GameOutcome, advance_bit_position::AdvanceBitPosition, array_position::ArrayPosition,
+        from_moves, try_load_starting_position,
+    },
+    random::Rng,
+};
+
+#[test]
+fn buckets_follow_the_datasets() {
+    assert_eq!(bucket(0, 41), (1, 3));
+    assert_eq!(bucket(14, 13), (1, 1));
+    assert_eq!(bucket(15, 14), (2, 2));
+    assert_eq!(bucket(28, 27), (2, 2));
+    assert_eq!(bucket(29, 28), (3, 3));
+    assert_eq!(bucket_name(30, 5), "Test_L3_R1");
+    assert_eq!(bucket_name(10, 20), "Test_L1_R2");
+}
+
+#[test]
+fn playouts_are_reproducible_and_unfinished() {
+    let playouts = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..100)
+            .filter_map(|_| random_playout(&mut rng, 30))
+            .collect::<Vec<_>>()
+    };
+    let games = playouts(3);
+    assert_eq!(games, playouts(3));
+    assert_ne!(games, playouts(4));
+    assert!(!games.is_empty());
+    for moves in &games {
+        assert_eq!(moves.len(), 30);
+        assert_eq!(
+            try_load_starting_position(moves, &mut ArrayPosition::new()),
+            Ok(GameOutcome::Ongoing)
+        );
+        assert!(!from_moves::<AdvanceBitPosition>(moves).can_win_next());
+    }
+    assert_eq!(random_playout(&mut Rng::new(1), 0), Some(String::new()));
+}
+
+#[test]
+fn lines_are_validated() {
+    assert_eq!(parse_line("4453 -2"), Ok(Some(("4453", -2))));
+    assert_eq!(parse_line("  "), Ok(None));
+    assert_eq!(
+        parse_line("4444444 0"),
+        Err("move 7: column 4 is full".to_string())
+    );
+    assert_eq!(
+        parse_line("4453 19"),
+        Err("'19' is not a score".to_string())
+    );
+    assert!(parse_line("4453").is_err());
+    assert!(parse_line("4453 1 2").is_err());
+}
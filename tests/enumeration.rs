@@ -0,0 +1,66 @@
+use project::{
+    enumeration::{EnumeratedPosition, enumerate_positions},
+    positions::{advance_bit_position::AdvanceBitPosition, load_starting_position},
+};
+
+// the number of legal positions after n moves published by John Tromp
+const POSITIONS: [usize; 9] = [1, 7, 49, 238, 1120, 4263, 16422, 54859, 184275];
+
+fn load(encoded_position: &str) -> AdvanceBitPosition {
+    let mut position = AdvanceBitPosition::new();
+    load_starting_position(encoded_position, &mut position);
+    position
+}
+
+fn empty_board() -> EnumeratedPosition {
+    EnumeratedPosition {
+        moves: String::new(),
+        position: AdvanceBitPosition::new(),
+    }
+}
+
+#[test]
+fn mirror_images() {
+    assert_eq!(load("1").mirror().key(), load("7").key());
+    assert_eq!(load("4453").mirror().key(), load("4435").key());
+    let position = load("766625717762765342316531145551237");
+    assert_eq!(position.mirror().mirror().key(), position.key());
+    assert_eq!(position.mirror().symmetric_key(), position.symmetric_key());
+}
+
+#[test]
+fn published_position_counts() {
+    let mut fixed = vec![0; POSITIONS.len()];
+    let counts = enumerate_positions(empty_board(), POSITIONS.len() - 1, false, |enumerated| {
+        // the moves lead to the enumerated position
+        assert_eq!(load(&enumerated.moves).key(), enumerated.position.key());
+        if enumerated.position.mirror().key() == enumerated.position.key() {
+            fixed[enumerated.moves.len()] += 1;
+        }
+    });
+    assert_eq!(counts, POSITIONS);
+
+    // every class of mirror images has two positions except the symmetric ones
+    let counts = enumerate_positions(empty_board(), POSITIONS.len() - 1, true, |_| {});
+    for ply in 0..POSITIONS.len() {
+        assert_eq!(counts[ply], (POSITIONS[ply] + fixed[ply]) / 2);
+    }
+}
+
+#[test]
+fn won_positions_are_not_expanded() {
+    // the player to move wins in the 3rd and the 7th column, the other columns keep the game going
+    let root = EnumeratedPosition {
+        moves: "445566".to_string(),
+        position: load("445566"),
+    };
+    let mut won = 0;
+    let counts = enumerate_positions(root, 8, false, |enumerated| {
+        if ["4455663", "4455667"].contains(&enumerated.moves.as_str()) {
+            won += 1;
+        }
+    });
+    assert_eq!(won, 2);
+    assert_eq!(counts[1], 7);
+    assert_eq!(counts[2], 5 * 7);
+}
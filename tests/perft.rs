@@ -0,0 +1,76 @@
+use project::{
+    perft::{perft, perft_unique},
+    positions::{
+        Position, advance_bit_position::AdvanceBitPosition, array_position::ArrayPosition,
+        bit_position::BitPosition, load_starting_position,
+    },
+};
+
+// no column is full and nobody can win in the first 6 moves, 7 sequences fill a column at move 7
+const SEQUENCES: [u64; 9] = [1, 7, 49, 343, 2401, 16807, 117649, 823536, 5673234];
+
+// the number of legal positions after n moves published by John Tromp
+const POSITIONS: [u64; 10] = [1, 7, 49, 238, 1120, 4263, 16422, 54859, 184275, 558186];
+
+fn load<P: Position + Default>(encoded_position: &str) -> P {
+    let mut position = P::default();
+    load_starting_position(encoded_position, &mut position);
+    position
+}
+
+#[test]
+fn sequences_from_the_empty_board() {
+    for (depth, &expected) in SEQUENCES.iter().enumerate().take(8) {
+        assert_eq!(perft(&mut ArrayPosition::new(), depth), expected);
+        assert_eq!(perft(&mut BitPosition::new(), depth), expected);
+        assert_eq!(perft(&mut AdvanceBitPosition::new(), depth), expected);
+    }
+    assert_eq!(perft(&mut AdvanceBitPosition::new(), 8), SEQUENCES[8]);
+}
+
+#[test]
+fn positions_from_the_empty_board() {
+    for (depth, &expected) in POSITIONS.iter().enumerate().take(8) {
+        assert_eq!(perft_unique(&mut ArrayPosition::new(), depth), expected);
+        assert_eq!(perft_unique(&mut BitPosition::new(), depth), expected);
+    }
+    for (depth, &expected) in POSITIONS.iter().enumerate() {
+        assert_eq!(
+            perft_unique(&mut AdvanceBitPosition::new(), depth),
+            expected
+        );
+    }
+}
+
+#[test]
+fn representations_agree_near_the_end() {
+    // wins and full columns cut the sequences short
+    for encoded_position in [
+        "445566",
+        "766625717762765342316531145551237",
+        "444444121253536213",
+    ] {
+        for depth in 1..=6 {
+            let sequences = perft(&mut load::<ArrayPosition>(encoded_position), depth);
+            assert_eq!(
+                perft(&mut load::<BitPosition>(encoded_position), depth),
+                sequences
+            );
+            assert_eq!(
+                perft(&mut load::<AdvanceBitPosition>(encoded_position), depth),
+                sequences
+            );
+
+            let positions = perft_unique(&mut load::<ArrayPosition>(encoded_position), depth);
+            assert!(positions <= sequences);
+            assert_eq!(
+                perft_unique(&mut load::<BitPosition>(encoded_position), depth),
+                positions
+            );
+            assert_eq!(
+                perft_unique(&mut load::<AdvanceBitPosition>(encoded_position), depth),
+                positions
+            );
+        }
+    }
+}
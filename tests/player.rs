@@ -0,0 +1,79 @@
+use project::{
+    analysis::best_column,
+    player::{Level, Player, Style},
+    solvers::registry::Strength,
+};
+
+const SCORES: [Option<i32>; 7] = [
+    Some(-5),
+    Some(2),
+    None,
+    Some(0),
+    Some(-1),
+    Some(7),
+    Some(-18),
+];
+
+fn choices(mut player: Player, scores: &[Option<i32>; 7]) -> Vec<usize> {
+    (0..200).map(|_| player.choose(scores).unwrap()).collect()
+}
+
+#[test]
+fn levels_are_reproducible() {
+    for level in Level::ALL {
+        assert_eq!(Level::from_name(level.name()), Some(level));
+        assert_eq!(
+            choices(Player::with_level(level, 42), &SCORES),
+            choices(Player::with_level(level, 42), &SCORES)
+        );
+    }
+    assert_eq!(Level::from_name("impossible"), None);
+    assert_eq!(Level::Perfect.strength(), Strength::Strong);
+    assert_eq!(Level::Hard.strength(), Strength::Weak);
+    assert_eq!(Player::with_level(Level::Easy, 1).choose(&[None; 7]), None);
+}
+
+#[test]
+fn styles_follow_the_scores() {
+    // perfect never deviates from the best column
+    let perfect = choices(Player::with_level(Level::Perfect, 7), &SCORES);
+    assert!(
+        perfect
+            .iter()
+            .all(|&colm| Some(colm) == best_column(&SCORES))
+    );
+
+    // without blunders only the non losing columns are played, and all of them are
+    let random = choices(Player::new(Style::RandomNonLosing, 0.0, 7), &SCORES);
+    assert!(random.iter().all(|colm| [1, 3, 5].contains(colm)));
+    assert!([1, 3, 5].iter().all(|colm| random.contains(colm)));
+
+    // every column loses, any of them is played
+    let losing = [Some(-3), None, None, None, None, None, Some(-1)];
+    let random = choices(Player::new(Style::RandomNonLosing, 0.0, 7), &losing);
+    assert!(random.contains(&0) && random.contains(&6));
+
+    // a cold softmax plays the best column, a hot one spreads over the playable ones
+    let cold = choices(
+        Player::new(Style::Softmax { temperature: 0.01 }, 0.0, 7),
+        &SCORES,
+    );
+    assert!(cold.iter().all(|&colm| colm == 5));
+    let hot = choices(
+        Player::new(
+            Style::Softmax {
+                temperature: 1000.0,
+            },
+            0.0,
+            7,
+        ),
+        &SCORES,
+    );
+    assert!(!hot.contains(&2));
+    assert!(hot.iter().filter(|&&colm| colm != 5).count() > 100);
+
+    // blunders ignore the scores, full columns excluded
+    let blunders = choices(Player::new(Style::Perfect, 1.0, 7), &SCORES);
+    assert!(!blunders.contains(&2));
+    assert!(blunders.contains(&6));
+}
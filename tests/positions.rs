@@ -0,0 +1,102 @@
+use project::{
+    positions::{
+        GameOutcome, Position, advance_bit_position::AdvanceBitPosition,
+        array_position::ArrayPosition, bit_position::BitPosition, load_starting_position,
+        position_history::PositionHistory, try_load_starting_position,
+    },
+    solvers::finished_game_score,
+};
+
+#[test]
+fn ongoing_game() {
+    let mut position = BitPosition::new();
+    assert_eq!(load_starting_position("4455", &mut position), GameOutcome::Ongoing);
+    assert_eq!(position.played_moves(), 4);
+    assert_eq!(finished_game_score(GameOutcome::Ongoing, 4), None);
+}
+
+#[test]
+fn finished_games() {
+    // the first player completes the first column with the 7th move
+    let mut position = AdvanceBitPosition::new();
+    let outcome = load_starting_position("1212121", &mut position);
+    assert_eq!(outcome, GameOutcome::Win(1));
+    assert_eq!(finished_game_score(outcome, 7), Some(-18));
+
+    // the second player completes the second column with the 8th move
+    let outcome = load_starting_position("12121232", &mut ArrayPosition::new());
+    assert_eq!(outcome, GameOutcome::Win(2));
+    assert_eq!(finished_game_score(outcome, 8), Some(-18));
+
+    let draw = "547125662261271266215743771576315353334444";
+    let outcome = load_starting_position(draw, &mut BitPosition::new());
+    assert_eq!(outcome, GameOutcome::Draw);
+    assert_eq!(finished_game_score(outcome, draw.len()), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "invalid starting position")]
+fn moves_after_a_win() {
+    load_starting_position("12121214", &mut BitPosition::new());
+}
+
+#[test]
+fn invalid_moves() {
+    let error = |moves| try_load_starting_position(moves, &mut BitPosition::new()).unwrap_err();
+    assert_eq!(error("12121214"), "move 8: the game is already over");
+    assert_eq!(error("1111111"), "move 7: column 1 is full");
+    assert_eq!(error("408"), "move 2: '0' is not a column");
+    assert_eq!(error("4 4"), "move 2: ' ' is not a column");
+    assert_eq!(try_load_starting_position("4455", &mut BitPosition::new()), Ok(GameOutcome::Ongoing));
+}
+
+#[test]
+fn history_and_undo() {
+    let mut position = PositionHistory::new(AdvanceBitPosition::new());
+    load_starting_position("4453", &mut position);
+    let key = position.position().key();
+
+    position.play(1);
+    assert_eq!(position.history(), &[3, 3, 4, 2, 1]);
+    assert_eq!(position.to_move_string(), "44532");
+    assert_eq!(position.undo(), Some(1));
+    assert_eq!(position.position().key(), key);
+
+    while position.undo().is_some() {}
+    assert_eq!(position.position().key(), 0);
+    assert_eq!(position.to_move_string(), "");
+}
+
+#[test]
+fn rendering() {
+    let mut position = PositionHistory::new(ArrayPosition::new());
+    load_starting_position("4453", &mut position);
+    let expected = concat!(
+        " . . . . . . .\n",
+        " . . . . . . .\n",
+        " . . . . . . .\n",
+        " . . . . . . .\n",
+        " . . . O . . .\n",
+        " . . O X X . .\n",
+        " 1 2 3 4 5 6 7\n",
+        "     ^\n",
+        "X to move, 4 played moves",
+    );
+    assert_eq!(position.to_string(), expected);
+}
+
+#[test]
+fn conversions() {
+    let mut array_position = ArrayPosition::new();
+    load_starting_position("3344556", &mut array_position);
+    let bit_position = BitPosition::from(array_position.clone());
+    let advance_bit_position = AdvanceBitPosition::from(bit_position);
+
+    let mut expected = AdvanceBitPosition::new();
+    load_starting_position("3344556", &mut expected);
+    assert_eq!(advance_bit_position.key(), expected.key());
+    assert_eq!(
+        ArrayPosition::from(advance_bit_position).to_string(),
+        array_position.to_string()
+    );
+}
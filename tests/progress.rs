@@ -0,0 +1,100 @@
+use std::sync::{Arc, Mutex};
+
+use project::solvers::{
+    progress::{Iteration, Observer, Progress, ScoreRange},
+    registry::{BuildOptions, build_solver_with},
+};
+
+const TABLE_SIZE: usize = 1048573;
+
+#[derive(Default)]
+struct Recorder {
+    iterations: Mutex<Vec<(Iteration, Progress)>>,
+    nodes: Mutex<Vec<Progress>>,
+}
+
+impl Observer for Recorder {
+    fn iteration(&self, iteration: &Iteration, progress: &Progress) {
+        self.iterations
+            .lock()
+            .unwrap()
+            .push((*iteration, *progress));
+    }
+
+    fn nodes(&self, progress: &Progress) {
+        self.nodes.lock().unwrap().push(*progress);
+    }
+
+    fn node_interval(&self) -> usize {
+        1000
+    }
+}
+
+fn observed_solve(solver: &str, position: &str) -> (i32, usize, Arc<Recorder>) {
+    let recorder = Arc::new(Recorder::default());
+    let options = BuildOptions {
+        observer: Some(recorder.clone()),
+        ..BuildOptions::new(TABLE_SIZE)
+    };
+    let (mut built, mode) = build_solver_with(solver, position, &options).unwrap();
+    let score = built.solve(mode);
+    (score, built.explored_nodes(), recorder)
+}
+
+#[test]
+fn iterations_and_node_counts() {
+    for solver in [
+        "strong-iterative-deepening",
+        "strong-avoid-losing-moves",
+        "strong-move-score",
+        "strong-mtdf",
+        "strong-pvs",
+    ] {
+        let (score, explored_nodes, recorder) = observed_solve(solver, "53174623526");
+        assert_eq!(score, 2, "{}", solver);
+
+        // the range of the score only narrows, down to the score
+        let iterations = recorder.iterations.lock().unwrap();
+        let (last, progress) = iterations.last().unwrap();
+        assert_eq!((last.left, last.right), (2, 2), "{}", solver);
+        assert_eq!(progress.explored_nodes, explored_nodes, "{}", solver);
+        for pair in iterations.windows(2) {
+            let ((before, _), (after, _)) = (pair[0], pair[1]);
+            assert!(
+                before.left <= after.left && after.right <= before.right,
+                "{}",
+                solver
+            );
+        }
+
+        let nodes = recorder.nodes.lock().unwrap();
+        assert_eq!(nodes.len(), explored_nodes / 1000, "{}", solver);
+        for (ind, progress) in nodes.iter().enumerate() {
+            assert_eq!(progress.explored_nodes, (ind + 1) * 1000);
+            assert_eq!(progress.table_size, TABLE_SIZE);
+            assert!(progress.table_fill() < 1.0);
+        }
+        assert!(
+            nodes
+                .windows(2)
+                .all(|pair| pair[0].table_filled <= pair[1].table_filled)
+        );
+    }
+
+    // the solvers without a root driver don't report anything
+    let (_, _, recorder) = observed_solve("strong-alpha-beta", "4444441212535362133");
+    assert!(recorder.iterations.lock().unwrap().is_empty());
+}
+
+#[test]
+fn score_ranges() {
+    // fail highs and fail lows narrow the range from each side, a score inside the window closes it
+    let mut range = ScoreRange::new(-10, 10);
+    let iteration = range.narrow(0, 1, 3);
+    assert_eq!((iteration.left, iteration.right), (3, 10));
+    let iteration = range.narrow(5, 6, 4);
+    assert_eq!((iteration.left, iteration.right), (3, 4));
+    let iteration = range.narrow(3, 4, 4);
+    assert_eq!((iteration.left, iteration.right), (4, 4));
+    assert_eq!(ScoreRange::new(-3, 3).narrow(-3, 3, 1).left, 1);
+}
@@ -0,0 +1,90 @@
+use project::{
+    positions::GameOutcome,
+    record::{GameRecord, RecordedMove, result_name},
+};
+
+#[test]
+fn moves_round_trip() {
+    let mut record = GameRecord::from_moves("4455667").unwrap();
+    record.set_header("Player1", "alice \"the first\"");
+    record.set_header("Date", "2026-10-19");
+    record.set_header("Player1", "alice");
+    assert_eq!(
+        record.to_string(),
+        "[Board \"7x6\"]\n[Player1 \"alice\"]\n[Date \"2026-10-19\"]\n[Result \"1-0\"]\n\n4 4 5 5 6 6 7\n"
+    );
+    assert_eq!(GameRecord::parse(&record.to_string()), Ok(record.clone()));
+    assert_eq!(record.to_moves(), "4455667");
+    assert_eq!(record.header("Result"), Some("1-0"));
+    assert_eq!(record.header("TimeControl"), None);
+
+    // a plain move string is a record without headers
+    let record = GameRecord::parse("4453\n").unwrap();
+    assert!(record.headers.is_empty());
+    assert_eq!(record.to_moves(), "4453");
+    assert_eq!(
+        GameRecord::from_moves("").unwrap().header("Result"),
+        Some("*")
+    );
+    assert_eq!(result_name(GameOutcome::Draw), "1/2-1/2");
+}
+
+#[test]
+fn scores_and_comments() {
+    let text = "[Player2 \"bob \\\\ \\\"the second\\\"\"]\n\n4 (0) {the center\nfirst} 4 5 {a \\} brace} 5 (-2)\n";
+    let record = GameRecord::parse(text).unwrap();
+    assert_eq!(record.header("Player2"), Some("bob \\ \"the second\""));
+    assert_eq!(
+        record.moves[..2],
+        [
+            RecordedMove {
+                colm: 3,
+                score: Some(0),
+                comment: Some("the center\nfirst".to_string())
+            },
+            RecordedMove {
+                colm: 3,
+                score: None,
+                comment: None
+            },
+        ]
+    );
+    assert_eq!(record.moves[2].comment.as_deref(), Some("a } brace"));
+    assert_eq!(record.moves[3].score, Some(-2));
+    assert_eq!(GameRecord::parse(&record.to_string()), Ok(record.clone()));
+
+    // long games are wrapped
+    let draw = GameRecord::from_moves("547125662261271266215743771576315353334444").unwrap();
+    let text = draw.to_string();
+    assert!(text.lines().all(|line| line.len() < 80));
+    assert_eq!(draw.header("Result"), Some("1/2-1/2"));
+    assert_eq!(GameRecord::parse(&text), Ok(draw));
+}
+
+#[test]
+fn invalid_records() {
+    let error = |text| GameRecord::parse(text).unwrap_err();
+    assert_eq!(
+        error("[Player1 alice]\n44"),
+        "line 1: a header is [Name \"value\"]"
+    );
+    assert_eq!(
+        error("[Date \"2026\"]\n\n44\n55 {a\ncomment}\n6 8"),
+        "line 6: '8' is not a column"
+    );
+    assert_eq!(error("44 {open"), "line 1: missing }");
+    assert_eq!(error("(1) 4"), "line 1: a score must follow a move");
+    assert_eq!(error("4 (x)"), "line 1: 'x' is not a score");
+    assert_eq!(error("12121214"), "move 8: the game is already over");
+    assert_eq!(
+        error("[Board \"8x8\"]\n4"),
+        "only 7x6 boards are supported, not 8x8"
+    );
+    assert_eq!(
+        error("[Result \"0-1\"]\n4455667"),
+        "the result is 0-1 but the moves end in 1-0"
+    );
+    // the game may have ended by resignation
+    assert!(GameRecord::parse("[Result \"0-1\"]\n4455").is_ok());
+    assert!(GameRecord::from_moves("1111111").is_err());
+}
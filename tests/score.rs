@@ -0,0 +1,48 @@
+use project::{
+    score::{Interpretation, Outcome},
+    solvers::SolveMode,
+};
+
+#[test]
+fn immediate_wins() {
+    // "445566", the first player wins with the 4th stone in the 7th column
+    let interpretation = Interpretation::new(18, 6, SolveMode::Strong);
+    assert_eq!(interpretation.outcome, Outcome::Win);
+    assert_eq!(interpretation.winner, Some(1));
+    assert_eq!(interpretation.winner_stones, Some(4));
+    assert_eq!(interpretation.plies_to_end, Some(1));
+    assert_eq!(interpretation.to_string(), "player 1 wins with stone 4 in 1 ply");
+
+    // the second player wins with the 10th stone, the last move of the game
+    let interpretation = Interpretation::new(12, 19, SolveMode::Strong);
+    assert_eq!(interpretation.winner, Some(2));
+    assert_eq!(interpretation.winner_stones, Some(10));
+    assert_eq!(interpretation.plies_to_end, Some(1));
+}
+
+#[test]
+fn losses_and_draws() {
+    // after 18 moves the first player loses if the second player gets 10 stones
+    let interpretation = Interpretation::new(-12, 18, SolveMode::Strong);
+    assert_eq!(interpretation.outcome, Outcome::Loss);
+    assert_eq!(interpretation.winner, Some(2));
+    assert_eq!(interpretation.winner_stones, Some(10));
+    assert_eq!(interpretation.plies_to_end, Some(2));
+    assert_eq!(interpretation.to_string(), "player 2 wins with stone 10 in 2 plies");
+
+    let interpretation = Interpretation::new(0, 30, SolveMode::Strong);
+    assert_eq!(interpretation.outcome, Outcome::Draw);
+    assert_eq!(interpretation.winner, None);
+    assert_eq!(interpretation.plies_to_end, Some(12));
+    assert_eq!(interpretation.to_string(), "draw in 12 plies");
+}
+
+#[test]
+fn weak_scores_only_tell_the_winner() {
+    let interpretation = Interpretation::new(1, 7, SolveMode::Weak);
+    assert_eq!(interpretation.winner, Some(2));
+    assert_eq!(interpretation.winner_stones, None);
+    assert_eq!(interpretation.plies_to_end, None);
+    assert_eq!(interpretation.to_string(), "player 2 wins");
+    assert_eq!(Outcome::of(-1).to_string(), "loss");
+}
@@ -0,0 +1,140 @@
+use project::{
+    score::Interpretation,
+    search_tree::SearchTree,
+    solvers::{
+        SolveMode,
+        registry::{BuildOptions, build_solver, build_solver_with},
+    },
+};
+
+const TABLE_SIZE: usize = 1048573;
+
+// a draw that takes about a hundred nodes to prove
+const POSITION: &str = "766625717762765342316531145551237";
+
+const TRACED: [&str; 8] = [
+    "strong-alpha-beta",
+    "strong-center-columns",
+    "strong-bitboard",
+    "strong-transposition-table",
+    "strong-iterative-deepening",
+    "strong-avoid-losing-moves",
+    "strong-move-score",
+    "strong-pvs",
+];
+
+fn traced_solve(solver: &str, max_depth: usize) -> (i32, usize, SearchTree) {
+    let mut options = BuildOptions::new(TABLE_SIZE);
+    options.search_tree_depth = Some(max_depth);
+    let (mut built, mode) = build_solver_with(solver, POSITION, &options).unwrap();
+    let score = built.solve(mode);
+    (
+        score,
+        built.explored_nodes(),
+        built.search_tree().unwrap().clone(),
+    )
+}
+
+fn depth(tree: &SearchTree, mut ind: usize) -> usize {
+    let mut depth = 0;
+    while let Some(parent) = tree.nodes()[ind].parent {
+        ind = parent;
+        depth += 1;
+    }
+    depth
+}
+
+#[test]
+fn trees_of_the_solvers() {
+    for solver in TRACED {
+        let (score, explored_nodes, tree) = traced_solve(solver, 2);
+        assert_eq!(score, 0, "{}", solver);
+
+        // every search of the root is a root node and the subtrees count every explored node
+        let roots: Vec<_> = tree
+            .nodes()
+            .iter()
+            .filter(|node| node.parent.is_none())
+            .collect();
+        assert!(!roots.is_empty(), "{}", solver);
+        let counted: usize = roots.iter().map(|root| root.subtree_nodes + 1).sum();
+        assert_eq!(counted, explored_nodes, "{}", solver);
+        assert_eq!(roots.last().unwrap().moves, POSITION);
+
+        for (ind, node) in tree.nodes().iter().enumerate() {
+            assert!(depth(&tree, ind) <= 2, "{}", solver);
+            assert!(node.value.is_some(), "{}", solver);
+            if let (Some(parent), Some(colm)) = (node.parent, node.colm) {
+                let parent = &tree.nodes()[parent];
+                assert_eq!(node.moves, format!("{}{}", parent.moves, colm + 1));
+                // the children search the negated window of the parent, narrowed by the siblings
+                assert!(
+                    -node.beta >= parent.alpha && -node.alpha <= parent.beta,
+                    "{}",
+                    solver
+                );
+            }
+        }
+    }
+
+    // the pruning techniques are visible in the trees
+    let (_, _, tree) = traced_solve("strong-alpha-beta", 3);
+    assert!(tree.nodes().iter().any(|node| node.cutoff.is_some()));
+    assert!(tree.nodes().iter().all(|node| node.table_bound.is_none()));
+    let (_, _, tree) = traced_solve("strong-transposition-table", 9);
+    assert!(tree.nodes().iter().any(|node| node.table_bound.is_some()));
+    let (_, _, tree) = traced_solve("strong-iterative-deepening", 0);
+    assert!(tree.nodes().len() > 1);
+    assert!(tree.nodes().iter().all(|node| node.parent.is_none()));
+}
+
+#[test]
+fn exports() {
+    let (_, _, tree) = traced_solve("strong-transposition-table", 2);
+    let edges = tree
+        .nodes()
+        .iter()
+        .filter(|node| node.parent.is_some())
+        .count();
+
+    let dot = tree.to_dot();
+    assert!(dot.starts_with("digraph search {\n") && dot.ends_with("}\n"));
+    assert_eq!(dot.matches(" -> n").count(), edges);
+    assert_eq!(dot.matches("[label=\"").count(), tree.nodes().len() + edges);
+    assert!(dot.contains(&format!("n0 [label=\"{}\\n[-4, 5] -> 0", POSITION)));
+
+    let json = tree.to_json();
+    assert!(json.starts_with(&format!(
+        "{{\n  \"root\": \"{}\",\n  \"max_depth\": 2,",
+        POSITION
+    )));
+    assert_eq!(json.matches("\"id\": ").count(), tree.nodes().len());
+    assert_eq!(json.matches("{").count(), json.matches("}").count());
+
+    // the score and its meaning head both formats once they are known
+    let tree = tree.with_result(0, Interpretation::new(0, POSITION.len(), SolveMode::Strong));
+    assert!(
+        tree.to_dot()
+            .contains("    label=\"score 0, draw in 9 plies\";\n")
+    );
+    assert!(tree.to_json().contains(
+        "  \"score\": 0,\n  \"interpretation\": {\"outcome\": \"draw\", \"winner\": null, \
+         \"winner_stones\": null, \"plies_to_end\": 9, \"text\": \"draw in 9 plies\"},\n  \"nodes\": ["
+    ));
+
+    let empty = SearchTree::new("", 1);
+    assert_eq!(
+        empty.to_json(),
+        "{\n  \"root\": \"\",\n  \"max_depth\": 1,\n  \"nodes\": []\n}\n"
+    );
+}
+
+#[test]
+fn trees_are_opt_in() {
+    let (mut built, mode) = build_solver("strong-move-score", POSITION, TABLE_SIZE).unwrap();
+    built.solve(mode);
+    assert!(built.search_tree().unwrap().nodes().is_empty());
+
+    let (built, _) = build_solver("negamax", "4", TABLE_SIZE).unwrap();
+    assert!(built.search_tree().is_none());
+}
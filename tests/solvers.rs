@@ -0,0 +1,216 @@
+use project::solvers::{
+    MAX_SCORE, MIN_SCORE, SolveMode,
+    registry::{
+        BuildOptions, SOLVERS, Strength, build_solver, build_solver_with, find_solver, variants,
+    },
+    root_driver::RootDriver,
+};
+
+// small prime, the samples are easy enough and every solve allocates its own table
+const TABLE_SIZE: usize = 1048573;
+
+// samples in the format of the downloaded datasets: "moves score" on each line
+const DATASETS: [(&str, &str); 6] = [
+    ("Test_L1_R1", include_str!("data/Test_L1_R1")),
+    ("Test_L1_R2", include_str!("data/Test_L1_R2")),
+    ("Test_L1_R3", include_str!("data/Test_L1_R3")),
+    ("Test_L2_R1", include_str!("data/Test_L2_R1")),
+    ("Test_L2_R2", include_str!("data/Test_L2_R2")),
+    ("Test_L3_R1", include_str!("data/Test_L3_R1")),
+];
+
+fn dataset(name: &str) -> Vec<(&'static str, i32)> {
+    let (_, content) = DATASETS
+        .iter()
+        .find(|(dataset_name, _)| *dataset_name == name)
+        .expect("unknown dataset");
+    content
+        .lines()
+        .map(|line| {
+            let (position, score) = line.split_once(' ').expect("invalid dataset line");
+            (position, score.parse().expect("invalid dataset score"))
+        })
+        .collect()
+}
+
+// weak solvers only have to find out who wins, strong ones the exact score
+fn check_solver(solver: &str, datasets: &[&str]) {
+    let (_, strength) = find_solver(solver).expect("unknown solver");
+    for &name in datasets {
+        for (position, expected) in dataset(name) {
+            let (mut built, mode) = build_solver(solver, position, TABLE_SIZE).expect("unknown solver");
+            let score = built.solve(mode);
+            if strength == Strength::Weak {
+                assert_eq!(
+                    score.signum(),
+                    expected.signum(),
+                    "{} on {} from {}",
+                    solver,
+                    position,
+                    name
+                );
+            } else {
+                assert_eq!(score, expected, "{} on {} from {}", solver, position, name);
+            }
+        }
+    }
+}
+
+#[test]
+fn registry_keeps_the_benchmark_names() {
+    let names: Vec<String> = variants().map(|(name, _, _)| name).collect();
+    let mut unique = names.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), names.len());
+
+    for name in ["negamax", "weak-alpha-beta", "strong-move-score"] {
+        assert!(names.iter().any(|registered| registered == name), "{}", name);
+    }
+    assert!(find_solver("move-score").is_none());
+    assert!(build_solver("unknown", "", TABLE_SIZE).is_none());
+}
+
+#[test]
+fn solve_modes() {
+    for entry in SOLVERS {
+        for (position, expected) in dataset("Test_L3_R1").into_iter().take(10) {
+            let mut solver = entry.build(position, &BuildOptions::new(TABLE_SIZE));
+            assert_eq!(solver.solve(SolveMode::Strong), expected, "{} on {}", entry.name, position);
+            assert!(solver.is_at_least(expected), "{} on {}", entry.name, position);
+            assert!(!solver.is_at_least(expected + 1), "{} on {}", entry.name, position);
+
+            let score = solver.solve(SolveMode::Window(expected - 2, expected + 2));
+            assert_eq!(score, expected, "{} on {}", entry.name, position);
+            let score = solver.solve(SolveMode::Window(expected, expected + 3));
+            assert!(score <= expected, "{} on {}", entry.name, position);
+            let score = solver.solve(SolveMode::Window(expected - 3, expected));
+            assert!(score >= expected, "{} on {}", entry.name, position);
+        }
+    }
+}
+
+#[test]
+fn negamax() {
+    check_solver("negamax", &["Test_L3_R1"]);
+}
+
+#[test]
+fn alpha_beta() {
+    check_solver("weak-alpha-beta", &["Test_L3_R1"]);
+    check_solver("strong-alpha-beta", &["Test_L3_R1"]);
+}
+
+#[test]
+fn center_columns() {
+    check_solver("weak-center-columns", &["Test_L3_R1"]);
+    check_solver("strong-center-columns", &["Test_L3_R1"]);
+}
+
+#[test]
+fn bitboard() {
+    check_solver("weak-bitboard", &["Test_L3_R1", "Test_L2_R1"]);
+    check_solver("strong-bitboard", &["Test_L3_R1", "Test_L2_R1"]);
+}
+
+#[test]
+fn transposition_table() {
+    check_solver("weak-transposition-table", &["Test_L3_R1", "Test_L2_R1"]);
+    check_solver("strong-transposition-table", &["Test_L3_R1", "Test_L2_R1"]);
+}
+
+#[test]
+fn iterative_deepening() {
+    check_solver("weak-iterative-deepening", &["Test_L3_R1", "Test_L2_R1", "Test_L1_R1"]);
+    check_solver("strong-iterative-deepening", &["Test_L3_R1", "Test_L2_R1", "Test_L1_R1"]);
+}
+
+#[test]
+fn avoid_losing_moves() {
+    let datasets = ["Test_L3_R1", "Test_L2_R1", "Test_L1_R1"];
+    check_solver("weak-avoid-losing-moves", &datasets);
+    check_solver("strong-avoid-losing-moves", &datasets);
+}
+
+#[test]
+fn move_score() {
+    let datasets = ["Test_L3_R1", "Test_L2_R1", "Test_L2_R2", "Test_L1_R1"];
+    check_solver("weak-move-score", &datasets);
+    check_solver("strong-move-score", &datasets);
+}
+
+#[test]
+fn pvs() {
+    let datasets = ["Test_L3_R1", "Test_L2_R1", "Test_L2_R2", "Test_L1_R1"];
+    check_solver("weak-pvs", &datasets);
+    check_solver("strong-pvs", &datasets);
+}
+
+#[test]
+fn move_ordering_heuristics() {
+    let datasets = ["Test_L3_R1", "Test_L2_R1", "Test_L1_R1"];
+    for name in [
+        "killer-moves",
+        "history-heuristic",
+        "killer-history",
+        "hash-move",
+    ] {
+        check_solver(&format!("weak-{}", name), &datasets);
+        check_solver(&format!("strong-{}", name), &datasets);
+    }
+}
+
+#[test]
+fn root_drivers() {
+    let drivers = [
+        RootDriver::Bisection,
+        RootDriver::Mtdf { guess: 0 },
+        RootDriver::Mtdf { guess: 7 },
+        RootDriver::Aspiration { guess: 0, delta: 2 },
+        RootDriver::Aspiration { guess: -5, delta: 1 },
+    ];
+    for driver in drivers {
+        for (alpha, beta) in [(MIN_SCORE, MAX_SCORE), (-1, 1)] {
+            for score in MIN_SCORE..=MAX_SCORE {
+                // a fail-hard search that knows the exact score
+                let found = driver.search(alpha, beta, |alpha, beta| score.clamp(alpha, beta));
+                assert_eq!(found, score.clamp(alpha, beta), "{:?} {}", driver, score);
+            }
+        }
+    }
+
+    let datasets = ["Test_L3_R1", "Test_L2_R1", "Test_L1_R1"];
+    for name in [
+        "mtdf",
+        "aspiration",
+        "iterative-deepening-mtdf",
+        "iterative-deepening-aspiration",
+        "avoid-losing-moves-mtdf",
+        "avoid-losing-moves-aspiration",
+    ] {
+        check_solver(&format!("weak-{}", name), &datasets);
+        check_solver(&format!("strong-{}", name), &datasets);
+    }
+
+    // a bad guess costs searches, not the score
+    for (position, expected) in dataset("Test_L3_R1").into_iter().take(20) {
+        for solver in ["strong-mtdf", "strong-avoid-losing-moves-aspiration"] {
+            for guess in [MIN_SCORE, 5, MAX_SCORE] {
+                let options = BuildOptions {
+                    guess,
+                    ..BuildOptions::new(TABLE_SIZE)
+                };
+                let (mut built, mode) = build_solver_with(solver, position, &options).unwrap();
+                assert_eq!(built.solve(mode), expected, "{} guessing {}", solver, guess);
+            }
+        }
+    }
+}
+
+// the opening samples take minutes without optimizations, run with --release -- --ignored
+#[test]
+#[ignore]
+fn move_score_openings() {
+    check_solver("weak-move-score", &["Test_L1_R2", "Test_L1_R3"]);
+    check_solver("strong-move-score", &["Test_L1_R2", "Test_L1_R3"]);
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use project::{
+    positions::{
+        Position, WIDTH, advance_bit_position::AdvanceBitPosition, load_starting_position,
+    },
+    solvers::{
+        SolveMode,
+        registry::{BuildOptions, build_solver, build_solver_with},
+    },
+    tablebase::Tablebase,
+};
+
+const TABLE_SIZE: usize = 1048573;
+
+fn load(encoded_position: &str) -> AdvanceBitPosition {
+    let mut position = AdvanceBitPosition::new();
+    load_starting_position(encoded_position, &mut position);
+    position
+}
+
+fn solve(encoded_position: &str, options: &BuildOptions) -> i32 {
+    let (mut solver, _) =
+        build_solver_with("strong-move-score", encoded_position, options).unwrap();
+    solver.solve(SolveMode::Strong)
+}
+
+#[test]
+fn scores_agree_with_the_solver() {
+    // 26 stones, the first player wins with the 14th stone
+    let root = "23153341215236762755665661";
+    let tablebase = Tablebase::build(load(root), 28);
+    assert_eq!(tablebase.min_stones(), 28);
+    assert!(!tablebase.is_empty());
+
+    // the positions reachable in 2 moves are stored, the root and its children aren't
+    let plain = BuildOptions::new(TABLE_SIZE);
+    assert_eq!(tablebase.probe(&load(root)), None);
+    for first in 1..=WIDTH {
+        for second in 1..=WIDTH {
+            let encoded_position = format!("{}{}{}", root, first, second);
+            let mut position = load(root);
+            let playable = [first, second].iter().all(|&colm| {
+                let playable = position.can_play(colm - 1) && !position.is_winning(colm - 1);
+                if playable {
+                    position.play(colm - 1);
+                }
+                playable
+            });
+            if !playable {
+                continue;
+            }
+            let expected = solve(&encoded_position, &plain);
+            assert_eq!(
+                tablebase.probe(&position),
+                Some(expected),
+                "{}",
+                encoded_position
+            );
+        }
+    }
+
+    let options = BuildOptions {
+        tablebase: Some(Arc::new(tablebase)),
+        ..BuildOptions::new(TABLE_SIZE)
+    };
+    assert_eq!(solve(root, &options), 8);
+}
+
+#[test]
+fn probing_cuts_the_search_short() {
+    let root = "2742725366521625511767336";
+    let options = BuildOptions {
+        tablebase: Some(Arc::new(Tablebase::build(load(root), 29))),
+        ..BuildOptions::new(TABLE_SIZE)
+    };
+    for name in ["strong-move-score", "strong-pvs"] {
+        let (mut solver, mode) = build_solver(name, root, TABLE_SIZE).unwrap();
+        assert_eq!(solver.solve(mode), 3);
+        let plain_nodes = solver.explored_nodes();
+
+        let (mut solver, mode) = build_solver_with(name, root, &options).unwrap();
+        assert_eq!(solver.solve(mode), 3);
+        assert!(solver.explored_nodes() < plain_nodes, "{}", name);
+    }
+}
+
+#[test]
+fn saved_tablebase_loads_back() {
+    let root = "23153341215236762755665661";
+    let tablebase = Tablebase::build(load(root), 30);
+    let path = std::env::temp_dir().join(format!("tablebase-test-{}", std::process::id()));
+    tablebase.save(&path).unwrap();
+    let loaded = Tablebase::load(&path).unwrap();
+
+    // an entry count that doesn't match the file is rejected before anything is allocated
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[5..13].copy_from_slice(&u64::MAX.to_le_bytes());
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(Tablebase::load(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.min_stones(), 30);
+    assert_eq!(loaded.len(), tablebase.len());
+
+    // follow the leftmost moves that don't end the game down to the stored positions
+    let mut position = load(root);
+    while position.played_moves() < 30 {
+        let colm = (0..WIDTH)
+            .find(|&colm| position.can_play(colm) && !position.is_winning(colm))
+            .unwrap();
+        position.play(colm);
+    }
+    assert!(loaded.probe(&position).is_some());
+    assert_eq!(loaded.probe(&position), tablebase.probe(&position));
+}
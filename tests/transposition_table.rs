@@ -0,0 +1,40 @@
+use project::transposition_table::TranspositionTable;
+
+#[test]
+fn empty_board_is_not_stored_initially() {
+    // the empty board has key 0, the same bits as an empty slot
+    let mut table = TranspositionTable::new(1021);
+    assert_eq!(table.get(0), None);
+    assert_eq!(table.get_move(0), None);
+    assert_eq!(table.filled(), 0);
+
+    table.put(0, 19);
+    assert_eq!(table.get(0), Some(19));
+    assert_eq!(table.get_move(0), None);
+    assert_eq!((table.filled(), table.size()), (1, 1021));
+}
+
+#[test]
+fn value_and_best_move_are_kept_together() {
+    let mut table = TranspositionTable::new(1021);
+    let key = (1 << 48) | 12345;
+
+    table.put_move(key, 6);
+    assert_eq!(table.get(key), None);
+    assert_eq!(table.get_move(key), Some(6));
+
+    table.put(key, 7);
+    assert_eq!(table.get(key), Some(7));
+    assert_eq!(table.get_move(key), Some(6));
+
+    table.put_move(key, 0);
+    assert_eq!(table.get(key), Some(7));
+    assert_eq!(table.get_move(key), Some(0));
+
+    // a different position in the same slot replaces both
+    table.put(key + 1021, 3);
+    assert_eq!(table.get(key), None);
+    assert_eq!(table.get_move(key), None);
+    assert_eq!(table.get_move(key + 1021), None);
+    assert_eq!(table.filled(), 1);
+}